@@ -1,6 +1,12 @@
-use window_getter::platform_impl::macos::permission;
+#[cfg(not(target_os = "macos"))]
+fn main() {
+    panic!("This example only supports macOS.");
+}
 
+#[cfg(target_os = "macos")]
 fn main() {
+    use window_getter::platform_impl::macos::permission;
+
     let screen_capture_access = permission::has_screen_capture_access();
     println!("screen capture access: {screen_capture_access}");
 
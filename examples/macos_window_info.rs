@@ -6,7 +6,10 @@ fn main() {
 #[cfg(target_os = "macos")]
 fn main() {
     for window in window_getter::get_windows().unwrap() {
-        let window_info = window.into_platform_window().into_window_info();
+        let Some(window) = window.into_platform_window() else {
+            continue;
+        };
+        let window_info = window.into_window_info();
 
         println!(
             "{:?} ({}) memory usage: {} bytes",
@@ -6,7 +6,9 @@ fn main() {
 #[cfg(target_os = "windows")]
 fn main() {
     for window in window_getter::get_windows().unwrap() {
-        let window = window.into_platform_window();
+        let Some(window) = window.into_platform_window() else {
+            continue;
+        };
 
         println!("\n{:?} ({:?})", window.title(), window.hwnd());
         println!("\tGetWindowRect: {:?}", window.rect());
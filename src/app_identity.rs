@@ -0,0 +1,26 @@
+use std::path::PathBuf;
+
+/// The identity of the application which owns a window, returned by
+/// [`Window::app_id`](crate::Window::app_id).
+///
+/// It can be used to map the windows to the `.desktop` files on Linux,
+/// the bundles on macOS or the executables on Windows, e.g. to find their icons.
+/// Each field is `None` if it is not provided by the platform or could not be read.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AppIdentity {
+    /// The identifier of the application.
+    ///
+    /// # Platform-specific
+    /// - **Windows**: It is always `None`.
+    /// - **macOS**: It is the bundle identifier, e.g. `com.apple.Safari`.
+    /// - **Linux (X11)**: It is the class of `WM_CLASS`, e.g. `firefox`.
+    /// - **Linux (Wayland)**: It is the application ID sent by the compositor, which usually
+    ///   matches the name of the `.desktop` file, e.g. `org.mozilla.firefox`.
+    pub id: Option<String>,
+    /// The instance name of `WM_CLASS`. It is only provided on X11.
+    pub instance: Option<String>,
+    /// The path of the executable file of the process which owns the window.
+    /// It is not provided on Wayland, which does not expose the process.
+    pub executable: Option<PathBuf>,
+}
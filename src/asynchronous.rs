@@ -0,0 +1,220 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    panic,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use futures_core::Stream;
+use tokio::{
+    task::JoinHandle,
+    time::{Interval, MissedTickBehavior},
+};
+
+use crate::{Error, PlatformBackend, Window, WindowBackend, WindowEvent, WindowId, WindowWatcher};
+
+/// Retrieves a window by its unique identifier without blocking the runtime.
+///
+/// It calls [`get_window`](crate::get_window) on the blocking thread pool of Tokio,
+/// except when a `MockDesktop` of the `mock` feature is installed on the current thread.
+///
+/// # Platform-specific
+/// The blocking thread pool is used on all platforms. The APIs of **Windows** and **macOS**
+/// are synchronous, and the requests to the X server or the Wayland compositor on **Linux**
+/// wait for their replies.
+pub async fn get_window_async(id: WindowId) -> Result<Option<Window>, Error> {
+    run_blocking(move |backend| backend.get_window(id)).await
+}
+
+/// Retrieves a list of all open windows on the system without blocking the runtime.
+///
+/// It calls [`get_windows`](crate::get_windows) on the blocking thread pool of Tokio,
+/// except when a `MockDesktop` of the `mock` feature is installed on the current thread.
+///
+/// # Platform-specific
+/// The blocking thread pool is used on all platforms. The APIs of **Windows** and **macOS**
+/// are synchronous, and the requests to the X server or the Wayland compositor on **Linux**
+/// wait for their replies.
+pub async fn get_windows_async() -> Result<Vec<Window>, Error> {
+    run_blocking(|backend| backend.get_windows()).await
+}
+
+/// Watches the windows of the running system as a [`Stream`] of [`WindowEvent`]s.
+///
+/// It is the asynchronous version of [`WindowWatcher::new`].
+/// Use [`WindowWatcher::into_stream`] to watch a custom backend or change the poll interval.
+///
+/// The watcher is created on the blocking thread pool of Tokio because it reads the windows.
+/// See [`WindowEventStream`] for how the windows are checked afterwards.
+///
+/// # Panics
+/// It panics when it is not called in a Tokio runtime with the time driver enabled.
+///
+/// # Examples
+/// ```no_run
+/// # async fn run() -> Result<(), window_getter::Error> {
+/// use tokio_stream::StreamExt;
+///
+/// let mut events = window_getter::watch().await?;
+/// while let Some(event) = events.next().await {
+///     println!("{:?}", event?);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn watch() -> Result<WindowEventStream, Error> {
+    #[cfg(feature = "mock")]
+    if let Some(desktop) = crate::mock::installed() {
+        return Ok(WindowWatcher::with_backend(desktop)?.into_stream());
+    }
+
+    let watcher = join(tokio::task::spawn_blocking(WindowWatcher::new)).await?;
+
+    Ok(watcher.into_stream())
+}
+
+/// A [`Stream`] of the events of a [`WindowWatcher`].
+/// It is created by [`watch`] or [`WindowWatcher::into_stream`].
+///
+/// The windows are checked on the blocking thread pool of Tokio when a notification is
+/// received or the poll interval elapses, so the runtime is never blocked.
+///
+/// # Platform-specific
+/// - **Linux (X11)**: The X11 connection is registered to the reactor of Tokio, and the events
+///   are read on the runtime when it becomes readable. Only the check of the windows runs on
+///   the blocking thread pool.
+/// - **Linux (Wayland)**: The Wayland display is registered to the reactor of Tokio, but the
+///   events are read on the blocking thread pool with the check of the windows because the
+///   connection is shared with [`get_windows`](crate::get_windows).
+/// - **Windows** / **macOS**: The windows are checked at the poll interval on the blocking
+///   thread pool because there are no notifications.
+///
+/// On Linux, if the IO driver is not enabled, the windows are only checked at the poll interval.
+#[derive(Debug)]
+pub struct WindowEventStream {
+    /// The watcher. It is `None` while it is checking the windows in `task`.
+    watcher: Option<WindowWatcher>,
+    task: Option<JoinHandle<CheckResult>>,
+    events: VecDeque<WindowEvent>,
+    #[cfg(target_os = "linux")]
+    notification: Option<tokio::io::unix::AsyncFd<std::os::fd::OwnedFd>>,
+    interval: Interval,
+}
+
+/// The watcher given back by the task checking the windows, with the result.
+type CheckResult = (WindowWatcher, Result<Vec<WindowEvent>, Error>);
+
+impl WindowEventStream {
+    pub(crate) fn new(watcher: WindowWatcher) -> Self {
+        let period = watcher.interval();
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        #[cfg(target_os = "linux")]
+        let notification = watcher
+            .notification_fd()
+            .and_then(|fd| fd.try_clone_to_owned().ok())
+            .and_then(|fd| tokio::io::unix::AsyncFd::new(fd).ok());
+
+        Self {
+            watcher: Some(watcher),
+            task: None,
+            events: VecDeque::new(),
+            #[cfg(target_os = "linux")]
+            notification,
+            interval,
+        }
+    }
+
+    /// Returns `true` if a notification is received or the poll interval elapsed.
+    /// Otherwise, `cx` is woken up when either happens.
+    fn poll_wakeup(&mut self, cx: &mut Context<'_>) -> bool {
+        let mut woken = false;
+
+        #[cfg(target_os = "linux")]
+        if let Some(notification) = &self.notification {
+            while let Poll::Ready(guard) = notification.poll_read_ready(cx) {
+                let Ok(mut guard) = guard else {
+                    woken = true;
+                    break;
+                };
+
+                // The readiness is cleared after the notifications are read, so `cx` is
+                // registered again by the next iteration.
+                let watcher = self
+                    .watcher
+                    .as_mut()
+                    .expect("the watcher is present while it is not checking the windows");
+                woken |= watcher.take_notifications().unwrap_or(true);
+                guard.clear_ready();
+            }
+        }
+
+        if self.interval.poll_tick(cx).is_ready() {
+            woken = true;
+        }
+
+        woken
+    }
+}
+
+impl Stream for WindowEventStream {
+    type Item = Result<WindowEvent, Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(event) = this.events.pop_front() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            if let Some(task) = &mut this.task {
+                let (watcher, result) = ready!(Pin::new(task).poll(cx))
+                    .unwrap_or_else(|error| panic::resume_unwind(error.into_panic()));
+                this.task = None;
+                this.watcher = Some(watcher);
+
+                match result {
+                    Ok(events) => this.events.extend(events),
+                    Err(error) => return Poll::Ready(Some(Err(error))),
+                }
+                continue;
+            }
+
+            if !this.poll_wakeup(cx) {
+                return Poll::Pending;
+            }
+
+            let mut watcher = this
+                .watcher
+                .take()
+                .expect("the watcher is present while it is not checking the windows");
+            this.task = Some(tokio::task::spawn_blocking(move || {
+                let result = watcher.poll_events();
+                (watcher, result)
+            }));
+        }
+    }
+}
+
+/// Runs `f` with the default backend on the blocking thread pool.
+/// The [`MockDesktop`](crate::mock::MockDesktop) is called directly because it is
+/// installed per thread and never blocks.
+async fn run_blocking<R: Send + 'static>(
+    f: impl FnOnce(&dyn WindowBackend) -> R + Send + 'static,
+) -> R {
+    #[cfg(feature = "mock")]
+    if let Some(desktop) = crate::mock::installed() {
+        return f(&desktop);
+    }
+
+    join(tokio::task::spawn_blocking(move || f(&PlatformBackend))).await
+}
+
+/// Waits for the blocking task, propagating its panic.
+async fn join<R>(task: JoinHandle<R>) -> R {
+    task.await
+        .unwrap_or_else(|error| panic::resume_unwind(error.into_panic()))
+}
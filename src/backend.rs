@@ -0,0 +1,438 @@
+use std::fmt::Debug;
+
+use crate::{
+    AppIdentity, Bounds, Error, Monitor, ProcessInfo, Window, WindowId, WindowKind, WindowQuery,
+    WindowSnapshot, WindowState, platform_impl::PlatformWindow,
+};
+
+/// A source of windows.
+///
+/// It is implemented by [`PlatformBackend`] for the windows of the running system.
+/// You can implement it for your own sources such as remote machines, recordings or
+/// test fakes, and create [`Window`]s from them by [`Window::from_backend`].
+pub trait WindowBackend {
+    /// Retrieves a list of all windows.
+    /// The windows should be ordered from front to back.
+    fn get_windows(&self) -> Result<Vec<Window>, Error>;
+
+    /// Retrieves a window by its unique identifier.
+    ///
+    /// The default implementation looks for the window in [`get_windows`](Self::get_windows).
+    fn get_window(&self, id: WindowId) -> Result<Option<Window>, Error> {
+        Ok(self
+            .get_windows()?
+            .into_iter()
+            .find(|window| window.id() == id))
+    }
+
+    /// Retrieves the window which has the keyboard focus.
+    ///
+    /// The default implementation looks for the window whose
+    /// [`is_focused`](BackendWindow::is_focused) returns `true` in
+    /// [`get_windows`](Self::get_windows).
+    fn get_foreground_window(&self) -> Result<Option<Window>, Error> {
+        Ok(self
+            .get_windows()?
+            .into_iter()
+            .find(|window| window.is_focused().unwrap_or(false)))
+    }
+
+    /// Retrieves the windows matching the query in front-to-back order.
+    ///
+    /// The default implementation checks [`WindowQuery::matches`] for each window of
+    /// [`get_windows`](Self::get_windows). Override it if the backend can filter the windows
+    /// more cheaply.
+    fn find_windows(&self, query: &WindowQuery) -> Result<Vec<Window>, Error> {
+        Ok(query.take_matches(self.get_windows()?, |window| query.matches(window)))
+    }
+
+    /// Retrieves the frontmost window matching the query which contains the point.
+    ///
+    /// The default implementation looks for the first window of
+    /// [`get_windows`](Self::get_windows) whose bounds contain the point, skipping the
+    /// windows whose [`is_on_screen`](BackendWindow::is_on_screen) returns `false`.
+    fn find_window_at(&self, query: &WindowQuery, x: f64, y: f64) -> Result<Option<Window>, Error> {
+        Ok(query.take_match_at(self.get_windows()?, x, y))
+    }
+
+    /// Retrieves the monitors on which the windows are.
+    fn get_monitors(&self) -> Result<Vec<Monitor>, Error> {
+        Err(Error::Unsupported("get_monitors"))
+    }
+}
+
+/// A window provided by a [`WindowBackend`].
+///
+/// Each method corresponds to the method of [`Window`] with the same name.
+/// The methods with a default implementation return [`Error::Unsupported`].
+pub trait BackendWindow: Debug + Send + Sync + 'static {
+    /// Returns the unique identifier of the window.
+    fn id(&self) -> WindowId;
+
+    /// Returns the title of the window.
+    fn title(&self) -> Result<Option<String>, Error>;
+
+    /// Returns the bounds of the window.
+    fn bounds(&self) -> Result<Bounds, Error>;
+
+    /// Returns the process ID of the window's owner.
+    fn owner_pid(&self) -> Result<i32, Error>;
+
+    /// Returns the name of the process that owns the window.
+    fn owner_name(&self) -> Result<Option<String>, Error>;
+
+    /// Checks if the window has the keyboard focus.
+    fn is_focused(&self) -> Result<bool, Error> {
+        Err(Error::Unsupported("is_focused"))
+    }
+
+    /// Checks if the window is on the screen.
+    fn is_on_screen(&self) -> Result<bool, Error> {
+        Err(Error::Unsupported("is_on_screen"))
+    }
+
+    /// Returns the state of the window.
+    fn state(&self) -> Result<WindowState, Error> {
+        Err(Error::Unsupported("state"))
+    }
+
+    /// Returns the monitor on which the window is.
+    fn monitor(&self) -> Result<Option<Monitor>, Error> {
+        Err(Error::Unsupported("monitor"))
+    }
+
+    /// Returns the information of the process which owns the window.
+    fn owner_process(&self) -> Result<ProcessInfo, Error> {
+        Err(Error::Unsupported("owner_process"))
+    }
+
+    /// Returns the identity of the application which owns the window.
+    fn app_id(&self) -> Result<AppIdentity, Error> {
+        Err(Error::Unsupported("app_id"))
+    }
+
+    /// Returns the type of the window.
+    fn kind(&self) -> Result<WindowKind, Error> {
+        Err(Error::Unsupported("kind"))
+    }
+
+    /// Returns the ID of the parent window.
+    fn parent(&self) -> Result<Option<WindowId>, Error> {
+        Err(Error::Unsupported("parent"))
+    }
+
+    /// Returns the ID of the owner window.
+    fn owner_window(&self) -> Result<Option<WindowId>, Error> {
+        Err(Error::Unsupported("owner_window"))
+    }
+
+    /// Returns the IDs of the child windows.
+    fn children(&self) -> Result<Vec<WindowId>, Error> {
+        Err(Error::Unsupported("children"))
+    }
+
+    /// Reads all the properties at once.
+    ///
+    /// The default implementation calls each getter in turn.
+    /// Override it if the backend can read the properties more consistently or efficiently.
+    fn snapshot(&self) -> WindowSnapshot {
+        WindowSnapshot::from_results(
+            self.id(),
+            self.title(),
+            self.bounds(),
+            self.owner_pid(),
+            self.owner_name(),
+        )
+    }
+}
+
+/// The backend for the windows of the running system.
+/// It is what [`get_windows`](crate::get_windows) and [`get_window`](crate::get_window) use.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PlatformBackend;
+
+impl WindowBackend for PlatformBackend {
+    fn get_windows(&self) -> Result<Vec<Window>, Error> {
+        crate::platform_impl::get_windows()
+    }
+
+    fn get_window(&self, id: WindowId) -> Result<Option<Window>, Error> {
+        #[cfg(any(target_os = "macos", target_os = "linux"))]
+        {
+            crate::platform_impl::get_window(*id.platform_window_id())
+        }
+        #[cfg(target_os = "windows")]
+        {
+            Ok(crate::platform_impl::get_window(*id.platform_window_id()))
+        }
+    }
+
+    fn get_foreground_window(&self) -> Result<Option<Window>, Error> {
+        #[cfg(any(target_os = "macos", target_os = "linux"))]
+        {
+            crate::platform_impl::get_foreground_window()
+        }
+        #[cfg(target_os = "windows")]
+        {
+            Ok(crate::platform_impl::get_foreground_window())
+        }
+    }
+
+    fn get_monitors(&self) -> Result<Vec<Monitor>, Error> {
+        crate::platform_impl::get_monitors()
+    }
+
+    #[cfg(target_os = "linux")]
+    fn find_windows(&self, query: &WindowQuery) -> Result<Vec<Window>, Error> {
+        crate::platform_impl::linux::find_windows(query)
+    }
+
+    #[cfg(target_os = "linux")]
+    fn find_window_at(&self, query: &WindowQuery, x: f64, y: f64) -> Result<Option<Window>, Error> {
+        crate::platform_impl::linux::find_window_at(query, x, y)
+    }
+
+    #[cfg(target_os = "windows")]
+    fn find_window_at(&self, query: &WindowQuery, x: f64, y: f64) -> Result<Option<Window>, Error> {
+        crate::platform_impl::windows::find_window_at(query, x, y)
+    }
+}
+
+impl BackendWindow for PlatformWindow {
+    fn id(&self) -> WindowId {
+        #[cfg(any(target_os = "macos", target_os = "linux"))]
+        {
+            WindowId(self.id())
+        }
+        #[cfg(target_os = "windows")]
+        {
+            WindowId(self.hwnd())
+        }
+    }
+
+    fn title(&self) -> Result<Option<String>, Error> {
+        #[cfg(target_os = "macos")]
+        {
+            Ok(self.title())
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            Ok(self.title()?)
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.title()
+        }
+    }
+
+    fn bounds(&self) -> Result<Bounds, Error> {
+        #[cfg(target_os = "macos")]
+        {
+            Ok(self.bounds()?)
+        }
+        #[cfg(target_os = "linux")]
+        {
+            self.bounds()
+        }
+        #[cfg(target_os = "windows")]
+        {
+            Ok(self.visible_bounds()?)
+        }
+    }
+
+    fn owner_pid(&self) -> Result<i32, Error> {
+        #[cfg(target_os = "macos")]
+        {
+            Ok(self.owner_pid())
+        }
+
+        #[cfg(any(target_os = "windows", target_os = "linux"))]
+        {
+            Ok(self.owner_pid()? as _)
+        }
+    }
+
+    fn owner_name(&self) -> Result<Option<String>, Error> {
+        #[cfg(target_os = "macos")]
+        {
+            Ok(self.owner_name())
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            Ok(self.owner_name().map(Some)?)
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.owner_name()
+        }
+    }
+
+    fn is_focused(&self) -> Result<bool, Error> {
+        #[cfg(target_os = "macos")]
+        {
+            let foreground = crate::platform_impl::get_foreground_window()?;
+            Ok(foreground.is_some_and(|window| window.id().as_u32() == self.id()))
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            Ok(self.is_foreground())
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.is_focused()
+        }
+    }
+
+    fn is_on_screen(&self) -> Result<bool, Error> {
+        #[cfg(any(target_os = "macos", target_os = "windows"))]
+        {
+            Ok(self.is_on_screen())
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.is_on_screen()
+        }
+    }
+
+    fn state(&self) -> Result<WindowState, Error> {
+        #[cfg(target_os = "macos")]
+        {
+            Err(Error::Unsupported("state"))
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            Ok(self.state()?)
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.state()
+        }
+    }
+
+    fn owner_process(&self) -> Result<ProcessInfo, Error> {
+        #[cfg(target_os = "macos")]
+        {
+            Ok(self.owner_process())
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            Ok(self.owner_process()?)
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.owner_process()
+        }
+    }
+
+    fn app_id(&self) -> Result<AppIdentity, Error> {
+        #[cfg(target_os = "macos")]
+        {
+            Ok(self.app_id())
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            Ok(self.app_id()?)
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.app_id()
+        }
+    }
+
+    fn kind(&self) -> Result<WindowKind, Error> {
+        #[cfg(any(target_os = "macos", target_os = "windows"))]
+        {
+            Ok(self.kind())
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.kind()
+        }
+    }
+
+    fn parent(&self) -> Result<Option<WindowId>, Error> {
+        #[cfg(target_os = "macos")]
+        {
+            Err(Error::Unsupported("parent"))
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            Ok(self.parent().map(WindowId))
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            Ok(self.parent()?.map(WindowId))
+        }
+    }
+
+    fn owner_window(&self) -> Result<Option<WindowId>, Error> {
+        #[cfg(target_os = "macos")]
+        {
+            Err(Error::Unsupported("owner_window"))
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            Ok(self.owner_window().map(WindowId))
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            Ok(self.owner_window()?.map(WindowId))
+        }
+    }
+
+    fn children(&self) -> Result<Vec<WindowId>, Error> {
+        #[cfg(target_os = "macos")]
+        {
+            Err(Error::Unsupported("children"))
+        }
+
+        #[cfg(any(target_os = "windows", target_os = "linux"))]
+        {
+            Ok(self.children()?.into_iter().map(WindowId).collect())
+        }
+    }
+
+    fn monitor(&self) -> Result<Option<Monitor>, Error> {
+        #[cfg(target_os = "macos")]
+        {
+            let bounds = BackendWindow::bounds(self)?;
+            Ok(crate::monitor::monitor_for_bounds(
+                crate::platform_impl::get_monitors()?,
+                &bounds,
+            ))
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            Ok(self.monitor()?)
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            self.monitor()
+        }
+    }
+
+    #[cfg(any(target_os = "windows", target_os = "linux"))]
+    fn snapshot(&self) -> WindowSnapshot {
+        self.snapshot()
+    }
+}
@@ -4,7 +4,8 @@ use crate::platform_impl::PlatformBounds;
 
 /// Represents the bounds of a window.
 /// It can be converted from platform-specific bounds types.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bounds {
     pub x: f64,
     pub y: f64,
@@ -12,6 +13,198 @@ pub struct Bounds {
     pub height: f64,
 }
 
+impl Bounds {
+    /// Creates new bounds.
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Returns the x coordinate of the right edge.
+    pub fn right(&self) -> f64 {
+        self.x + self.width
+    }
+
+    /// Returns the y coordinate of the bottom edge.
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    /// Returns `true` if the bounds have no area, i.e. the width or the height is
+    /// not positive.
+    pub fn is_empty(&self) -> bool {
+        !(self.width > 0.0 && self.height > 0.0)
+    }
+
+    /// Returns the area. It is zero if the bounds are [empty](Self::is_empty).
+    pub fn area(&self) -> f64 {
+        if self.is_empty() {
+            0.0
+        } else {
+            self.width * self.height
+        }
+    }
+
+    /// Returns the center point as `(x, y)`.
+    pub fn center(&self) -> (f64, f64) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    /// Checks if the point is inside the bounds.
+    ///
+    /// The left and top edges are inside and the right and bottom edges are outside,
+    /// so a point on the border of two adjacent bounds is only inside one of them.
+    pub fn contains_point(&self, x: f64, y: f64) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+
+    /// Checks if the other bounds are entirely inside these bounds.
+    /// [Empty](Self::is_empty) bounds are inside any bounds.
+    pub fn contains(&self, other: &Bounds) -> bool {
+        other.is_empty()
+            || (other.x >= self.x
+                && other.y >= self.y
+                && other.right() <= self.right()
+                && other.bottom() <= self.bottom())
+    }
+
+    /// Returns the overlapping area of the bounds.
+    /// It is `None` if the bounds do not overlap.
+    pub fn intersect(&self, other: &Bounds) -> Option<Bounds> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let bounds = Bounds::new(
+            x,
+            y,
+            self.right().min(other.right()) - x,
+            self.bottom().min(other.bottom()) - y,
+        );
+
+        (!bounds.is_empty()).then_some(bounds)
+    }
+
+    /// Returns the smallest bounds which contain both bounds.
+    /// [Empty](Self::is_empty) bounds are ignored.
+    pub fn union(&self, other: &Bounds) -> Bounds {
+        if other.is_empty() {
+            return self.clone();
+        }
+        if self.is_empty() {
+            return other.clone();
+        }
+
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+
+        Bounds::new(
+            x,
+            y,
+            self.right().max(other.right()) - x,
+            self.bottom().max(other.bottom()) - y,
+        )
+    }
+
+    /// Returns the parts of these bounds which are not covered by the other bounds,
+    /// as non-overlapping rectangles.
+    ///
+    /// The parts are the bands above and below the overlapping area, followed by
+    /// the bands on its left and right. Empty parts are omitted.
+    pub fn subtract(&self, other: &Bounds) -> Vec<Bounds> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        let Some(overlap) = self.intersect(other) else {
+            return vec![self.clone()];
+        };
+
+        [
+            Bounds::new(self.x, self.y, self.width, overlap.y - self.y),
+            Bounds::new(
+                self.x,
+                overlap.bottom(),
+                self.width,
+                self.bottom() - overlap.bottom(),
+            ),
+            Bounds::new(self.x, overlap.y, overlap.x - self.x, overlap.height),
+            Bounds::new(
+                overlap.right(),
+                overlap.y,
+                self.right() - overlap.right(),
+                overlap.height,
+            ),
+        ]
+        .into_iter()
+        .filter(|part| !part.is_empty())
+        .collect()
+    }
+
+    /// Returns the bounds moved by the offset.
+    pub fn translate(&self, dx: f64, dy: f64) -> Bounds {
+        Bounds::new(self.x + dx, self.y + dy, self.width, self.height)
+    }
+
+    /// Returns the bounds shrunk by `dx` on the left and right edges and by `dy` on
+    /// the top and bottom edges. Negative values grow the bounds.
+    ///
+    /// The width and height do not become negative. If the bounds are shrunk more than
+    /// their size, they become empty bounds at the center.
+    pub fn inset(&self, dx: f64, dy: f64) -> Bounds {
+        let (center_x, center_y) = self.center();
+        let width = (self.width - 2.0 * dx).max(0.0);
+        let height = (self.height - 2.0 * dy).max(0.0);
+
+        Bounds::new(
+            (self.x + dx).min(center_x),
+            (self.y + dy).min(center_y),
+            width,
+            height,
+        )
+    }
+
+    /// Returns the smallest integer rectangle containing the bounds as
+    /// `(x, y, width, height)`.
+    ///
+    /// The values are saturated to the range of the integer types.
+    pub fn round_out(&self) -> (i32, i32, u32, u32) {
+        let x = self.x.floor();
+        let y = self.y.floor();
+
+        (
+            x as i32,
+            y as i32,
+            (self.right().ceil() - x).max(0.0) as u32,
+            (self.bottom().ceil() - y).max(0.0) as u32,
+        )
+    }
+
+    /// Returns the integer rectangle whose edges are rounded to the nearest integers as
+    /// `(x, y, width, height)`.
+    ///
+    /// The values are saturated to the range of the integer types.
+    pub fn round(&self) -> (i32, i32, u32, u32) {
+        let x = self.x.round();
+        let y = self.y.round();
+
+        (
+            x as i32,
+            y as i32,
+            (self.right().round() - x).max(0.0) as u32,
+            (self.bottom().round() - y).max(0.0) as u32,
+        )
+    }
+}
+
+/// Converts an integer rectangle given as `(x, y, width, height)`.
+impl From<(i32, i32, u32, u32)> for Bounds {
+    fn from((x, y, width, height): (i32, i32, u32, u32)) -> Self {
+        Bounds::new(x.into(), y.into(), width.into(), height.into())
+    }
+}
+
 #[cfg(target_os = "macos")]
 impl From<PlatformBounds> for Bounds {
     fn from(value: PlatformBounds) -> Self {
@@ -57,3 +250,27 @@ impl From<Bounds> for PlatformBounds {
         }
     }
 }
+
+#[cfg(target_os = "linux")]
+impl From<PlatformBounds> for Bounds {
+    fn from(value: PlatformBounds) -> Self {
+        Self {
+            x: value.x as _,
+            y: value.y as _,
+            width: value.width as _,
+            height: value.height as _,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl From<Bounds> for PlatformBounds {
+    fn from(value: Bounds) -> Self {
+        PlatformBounds {
+            x: value.x as _,
+            y: value.y as _,
+            width: value.width as _,
+            height: value.height as _,
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use crate::{Bounds, WindowId, WindowSnapshot};
+
+/// The differences between two lists of [`WindowSnapshot`]s, computed by [`diff_snapshots`].
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapshotDiff {
+    /// The windows which are only in the new list, in the order of the new list.
+    pub added: Vec<WindowSnapshot>,
+    /// The windows which are only in the old list, in the order of the old list.
+    pub removed: Vec<WindowSnapshot>,
+    /// The windows in both lists whose properties or positions changed,
+    /// in the order of the new list.
+    pub changed: Vec<WindowChange>,
+}
+
+impl SnapshotDiff {
+    /// Returns `true` if nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// The changes of a window in both lists of a [`SnapshotDiff`].
+/// Each field is `None` if the property did not change.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowChange {
+    pub id: WindowId,
+    pub title: Option<Change<Option<String>>>,
+    pub bounds: Option<Change<Option<Bounds>>>,
+    pub owner_pid: Option<Change<Option<i32>>>,
+    pub owner_name: Option<Change<Option<String>>>,
+    /// The position in the front-to-back order among the windows in both lists.
+    /// The added and removed windows do not change the positions of the others.
+    pub position: Option<Change<usize>>,
+}
+
+/// A value which changed from `old` to `new`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Change<T> {
+    pub old: T,
+    pub new: T,
+}
+
+/// Computes the differences between two lists of snapshots in front-to-back order,
+/// e.g. the results of [`get_window_snapshots`](crate::get_window_snapshots) at different times.
+///
+/// The windows are matched by their [`WindowId`]. A property is not compared if it could
+/// not be read in either snapshot, i.e. its error is stored in [`WindowSnapshot::errors`],
+/// so a failure to read a property is not reported as a change.
+pub fn diff_snapshots(old: &[WindowSnapshot], new: &[WindowSnapshot]) -> SnapshotDiff {
+    let old_windows = old
+        .iter()
+        .map(|snapshot| (snapshot.id, snapshot))
+        .collect::<HashMap<_, _>>();
+    let new_windows = new
+        .iter()
+        .map(|snapshot| (snapshot.id, snapshot))
+        .collect::<HashMap<_, _>>();
+
+    let removed = old
+        .iter()
+        .filter(|snapshot| !new_windows.contains_key(&snapshot.id))
+        .cloned()
+        .collect();
+    let added = new
+        .iter()
+        .filter(|snapshot| !old_windows.contains_key(&snapshot.id))
+        .cloned()
+        .collect();
+
+    let old_positions = old
+        .iter()
+        .filter(|snapshot| new_windows.contains_key(&snapshot.id))
+        .enumerate()
+        .map(|(position, snapshot)| (snapshot.id, position))
+        .collect::<HashMap<_, _>>();
+
+    let changed = new
+        .iter()
+        .filter_map(|snapshot| Some((old_windows.get(&snapshot.id)?, snapshot)))
+        .enumerate()
+        .filter_map(|(position, (old, new))| {
+            let change = WindowChange {
+                id: new.id,
+                title: compare(
+                    &old.title,
+                    &new.title,
+                    old.errors.title.is_none() && new.errors.title.is_none(),
+                ),
+                bounds: compare(
+                    &old.bounds,
+                    &new.bounds,
+                    old.errors.bounds.is_none() && new.errors.bounds.is_none(),
+                ),
+                owner_pid: compare(
+                    &old.owner_pid,
+                    &new.owner_pid,
+                    old.errors.owner_pid.is_none() && new.errors.owner_pid.is_none(),
+                ),
+                owner_name: compare(
+                    &old.owner_name,
+                    &new.owner_name,
+                    old.errors.owner_name.is_none() && new.errors.owner_name.is_none(),
+                ),
+                position: compare(&old_positions[&new.id], &position, true),
+            };
+
+            change.has_changes().then_some(change)
+        })
+        .collect();
+
+    SnapshotDiff {
+        added,
+        removed,
+        changed,
+    }
+}
+
+impl WindowChange {
+    fn has_changes(&self) -> bool {
+        self.title.is_some()
+            || self.bounds.is_some()
+            || self.owner_pid.is_some()
+            || self.owner_name.is_some()
+            || self.position.is_some()
+    }
+}
+
+/// Returns the change of a property if it was read successfully in both snapshots.
+fn compare<T: Clone + PartialEq>(old: &T, new: &T, readable: bool) -> Option<Change<T>> {
+    (readable && old != new).then(|| Change {
+        old: old.clone(),
+        new: new.clone(),
+    })
+}
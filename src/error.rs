@@ -7,11 +7,30 @@ pub enum Error {
     NoWindowEnvironment,
     /// The error that occurs when you don't have permission to access the window property.
     /// This can happen on Windows.
-    /// It represents [`E_ACCESSDENIED`][hresult] of [`HRESULT`](windows::core::HRESULT).
+    /// It represents [`E_ACCESSDENIED`][hresult] of `HRESULT`.
     ///
     /// [hresult]: <https://learn.microsoft.com/en-us/windows/win32/seccrypto/common-hresult-values>
     #[error("You don't have permission to access the window property: {0}")]
     PermissionDenied(super::platform_impl::PlatformError),
+    /// The error that occurs when no backend to retrieve the windows from is usable.
+    /// This can happen on only Linux, when neither a Wayland compositor supporting
+    /// the foreign toplevel protocols nor an X server is available.
+    #[error(
+        "No usable window backend was found. Neither a supported Wayland compositor nor an X server is available."
+    )]
+    NoBackendAvailable,
+    /// The error that occurs when the window does not exist anymore.
+    #[error("The window `{}` does not exist.", .0.as_u32())]
+    WindowNotFound(crate::WindowId),
+    /// The error that occurs when the operation is not supported on the platform
+    /// or the backend in use. It holds the name of the operation.
+    /// For example, Wayland does not expose the bounds of the windows.
+    #[error("The operation `{0}` is not supported on this platform.")]
+    Unsupported(&'static str),
+    /// The error restored from a serialized [`WindowSnapshot`](crate::WindowSnapshot).
+    /// It holds the message of the original error, which cannot be deserialized itself.
+    #[error("{0}")]
+    Recorded(String),
     /// platform-specific error that can occur when interacting with the window environment.
     #[error("A platform-specific error occurred: {0}")]
     PlatformSpecificError(super::platform_impl::PlatformError),
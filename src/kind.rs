@@ -0,0 +1,36 @@
+/// The type of a window returned by [`Window::kind`](crate::Window::kind).
+///
+/// The platforms classify the windows differently, so it is only a rough classification.
+/// See [`Window::kind`](crate::Window::kind) for how it is determined on each platform.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindowKind {
+    /// A normal top-level window of an application.
+    Normal,
+    /// A dialog window, e.g. a message box or a file chooser.
+    Dialog,
+    /// A small persistent window such as a palette or a toolbox.
+    Utility,
+    /// A menu, e.g. a drop-down menu or a context menu.
+    Menu,
+    /// A tooltip.
+    Tooltip,
+    /// A notification, e.g. a bubble shown by the notification daemon.
+    Notification,
+    /// A splash screen shown while an application is starting.
+    Splash,
+    /// A dock or a panel, e.g. the taskbar or the menu bar.
+    Dock,
+    /// The desktop background or the desktop icons.
+    Desktop,
+    /// A window which does not fit in the other kinds.
+    Other,
+}
+
+impl WindowKind {
+    /// Checks if the kind is for the windows which users interact with as applications,
+    /// i.e. [`Normal`](Self::Normal) and [`Dialog`](Self::Dialog).
+    pub fn is_application(&self) -> bool {
+        matches!(self, Self::Normal | Self::Dialog)
+    }
+}
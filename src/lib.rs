@@ -1,13 +1,41 @@
 #![doc = include_str!("../README.md")]
 
+mod app_identity;
+#[cfg(feature = "tokio")]
+mod asynchronous;
+mod backend;
 mod bounds;
+mod diff;
 mod error;
+mod kind;
+#[cfg(feature = "mock")]
+pub mod mock;
+mod monitor;
 pub mod platform_impl;
+mod process;
+mod query;
+mod snapshot;
+mod state;
+mod visibility;
+mod watcher;
 mod window;
 mod window_id;
 
+pub use app_identity::AppIdentity;
+#[cfg(feature = "tokio")]
+pub use asynchronous::{WindowEventStream, get_window_async, get_windows_async, watch};
+pub use backend::{BackendWindow, PlatformBackend, WindowBackend};
 pub use bounds::Bounds;
+pub use diff::{Change, SnapshotDiff, WindowChange, diff_snapshots};
 pub use error::Error;
+pub use kind::WindowKind;
+pub use monitor::{CoordinateSpace, Monitor};
+pub use process::ProcessInfo;
+pub use query::WindowQuery;
+pub use snapshot::{SnapshotErrors, WindowSnapshot};
+pub use state::WindowState;
+pub use visibility::{WindowVisibility, compute_visibility};
+pub use watcher::{WindowEvent, WindowWatcher};
 pub use window::Window;
 pub use window_id::WindowId;
 
@@ -15,18 +43,118 @@ pub use window_id::WindowId;
 ///
 /// # Platform-specific
 /// - **windows:** It will always return [`Ok`].
+///
+/// If a `MockDesktop` of the `mock` feature is installed on the current thread,
+/// the window is retrieved from it.
 pub fn get_window(id: WindowId) -> Result<Option<Window>, Error> {
-    #[cfg(target_os = "macos")]
-    {
-        platform_impl::get_window(*id.platform_window_id())
-    }
-    #[cfg(target_os = "windows")]
-    {
-        Ok(platform_impl::get_window(*id.platform_window_id()))
-    }
+    with_default_backend(|backend| backend.get_window(id))
+}
+
+/// Retrieves the window which has the keyboard focus.
+///
+/// It returns `None` if no window has the focus.
+///
+/// # Platform-specific
+/// - **Windows**: It is the window returned by [`GetForegroundWindow`][fg].
+/// - **macOS**: It is the frontmost window on the screen in the normal window layer,
+///   because the focused window cannot be retrieved without the accessibility permission.
+/// - **Linux (X11)**: It is the window set to `_NET_ACTIVE_WINDOW` by the window manager.
+/// - **Linux (Wayland)**: It is the activated window of `zwlr_foreign_toplevel_manager_v1`.
+///   It will return [`Error::Unsupported`] when the compositor only supports
+///   `ext_foreign_toplevel_list_v1`.
+///
+/// [fg]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getforegroundwindow
+///
+/// If a `MockDesktop` of the `mock` feature is installed on the current thread,
+/// the window is retrieved from it.
+pub fn get_foreground_window() -> Result<Option<Window>, Error> {
+    with_default_backend(|backend| backend.get_foreground_window())
+}
+
+/// Retrieves the frontmost window which contains the point in screen coordinates.
+///
+/// It returns `None` if no window is at the point, including when the point is NaN or
+/// out of the range of the screen coordinates of the platform. The windows are checked
+/// in the order of [`get_windows`] by their [`bounds`](Window::bounds), skipping the
+/// windows which are not on the screen. Use [`WindowQuery::find_at`] to add conditions,
+/// e.g. to skip the windows of the current process by [`WindowQuery::exclude_own_process`].
+///
+/// # Platform-specific
+/// - **Windows**: The window is found by [`WindowFromPoint`][wfp] first.
+///   It does not find the windows which are disabled or hidden from hit-testing.
+/// - **Linux (X11)**: The window is found by `XTranslateCoordinates` on the root window first,
+///   which respects the stacking order and the shapes of the windows.
+/// - **Linux (Wayland)**: It will return [`Error::Unsupported`] because the bounds
+///   are not exposed.
+///
+/// [wfp]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-windowfrompoint
+///
+/// If a `MockDesktop` of the `mock` feature is installed on the current thread,
+/// the window is retrieved from it.
+pub fn get_window_at(x: f64, y: f64) -> Result<Option<Window>, Error> {
+    WindowQuery::new().find_at(x, y)
 }
 
 /// Retrieves a list of all open windows on the system.
+///
+/// The windows are ordered from front to back, so the first window is the topmost one.
+/// They include the docks, the tooltips and the desktop, so use
+/// [`WindowQuery::applications_only`] to get only the windows of applications.
+///
+/// # Platform-specific
+/// - **macOS**: The order is the one of [`CGWindowListCopyWindowInfo`][cg].
+/// - **Windows**: The order is the Z order in which [`EnumWindows`][enum] enumerates the windows.
+/// - **Linux (X11)**: The order is taken from `_NET_CLIENT_LIST_STACKING`, or the stacking
+///   order of the top-level windows when the window manager does not provide it.
+/// - **Linux (Wayland)**: The windows are in the order the compositor announced them
+///   because Wayland does not expose the stacking order.
+///
+/// [cg]: https://developer.apple.com/documentation/coregraphics/cgwindowlistcopywindowinfo(_:_:)
+/// [enum]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumwindows
+///
+/// If a `MockDesktop` of the `mock` feature is installed on the current thread,
+/// the windows are retrieved from it.
 pub fn get_windows() -> Result<Vec<Window>, Error> {
-    platform_impl::get_windows()
+    with_default_backend(|backend| backend.get_windows())
+}
+
+/// Retrieves a list of the monitors connected to the system.
+///
+/// # Platform-specific
+/// - **Windows**: The monitors are enumerated by [`EnumDisplayMonitors`][edm], and the scale factor
+///   is the effective DPI divided by 96.
+/// - **macOS**: The active displays are enumerated by `CGGetActiveDisplayList`.
+///   The work area is the same as the bounds because it is only provided by AppKit.
+/// - **Linux (X11)**: The monitors are enumerated by RandR 1.5, or the screen is returned as
+///   the only monitor if the X server does not support it. The work area is taken from
+///   `_NET_WORKAREA` and the scale factor from `Xft.dpi` divided by 96.
+/// - **Linux (Wayland)**: The monitors are the `wl_output`s, whose bounds are taken from
+///   `zxdg_output_manager_v1` if the compositor supports it. The work area is the same as
+///   the bounds, and no monitor is primary because Wayland does not expose them.
+///
+/// [edm]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumdisplaymonitors
+///
+/// If a `MockDesktop` of the `mock` feature is installed on the current thread,
+/// the monitors are retrieved from it.
+pub fn get_monitors() -> Result<Vec<Monitor>, Error> {
+    with_default_backend(|backend| backend.get_monitors())
+}
+
+/// Retrieves a list of the snapshots of all open windows on the system.
+///
+/// It is the same as calling [`Window::snapshot`] for each window of [`get_windows`].
+pub fn get_window_snapshots() -> Result<Vec<WindowSnapshot>, Error> {
+    Ok(get_windows()?.iter().map(Window::snapshot).collect())
+}
+
+/// Calls `f` with the backend used by the functions of this crate, which is the
+/// [`MockDesktop`](mock::MockDesktop) installed on the current thread if any,
+/// or [`PlatformBackend`] otherwise.
+pub(crate) fn with_default_backend<R>(f: impl FnOnce(&dyn WindowBackend) -> R) -> R {
+    #[cfg(feature = "mock")]
+    if let Some(desktop) = mock::installed() {
+        return f(&desktop);
+    }
+
+    f(&PlatformBackend)
 }
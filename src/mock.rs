@@ -0,0 +1,416 @@
+//! In-memory windows for deterministic tests.
+//!
+//! [`MockDesktop`] holds a list of [`MockWindow`]s declared by tests.
+//! While it is installed by [`MockDesktop::install`], [`get_windows`](crate::get_windows)
+//! and [`get_window`](crate::get_window) read from it instead of the running system,
+//! so the code consuming this crate can be tested without a desktop.
+//!
+//! # Examples
+//! ```
+//! use window_getter::mock::{MockDesktop, MockWindow};
+//!
+//! let desktop = MockDesktop::new()
+//!     .with_window(MockWindow::new(1).title("Editor").owner(100, "editor"))
+//!     .with_window(MockWindow::new(2).title("Terminal").z_index(1).focused(true));
+//! let _guard = desktop.install();
+//!
+//! let windows = window_getter::get_windows().unwrap();
+//! assert_eq!(windows[0].title().unwrap().as_deref(), Some("Terminal"));
+//!
+//! desktop.update_window(1.into(), |window| window.title = Some("Editor - main.rs".into()));
+//! assert_eq!(
+//!     windows[1].title().unwrap().as_deref(),
+//!     Some("Editor - main.rs")
+//! );
+//! ```
+
+use std::{
+    cell::RefCell,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use crate::{
+    AppIdentity, BackendWindow, Bounds, Error, Monitor, ProcessInfo, Window, WindowBackend,
+    WindowId, WindowKind, WindowState,
+};
+
+thread_local! {
+    static INSTALLED: RefCell<Option<MockDesktop>> = const { RefCell::new(None) };
+}
+
+/// Returns the desktop installed on the current thread.
+pub(crate) fn installed() -> Option<MockDesktop> {
+    INSTALLED.with(|installed| installed.borrow().clone())
+}
+
+/// A window declared on a [`MockDesktop`].
+#[derive(Clone, Debug)]
+pub struct MockWindow {
+    pub id: WindowId,
+    pub title: Option<String>,
+    pub bounds: Bounds,
+    pub owner_pid: i32,
+    pub owner_name: Option<String>,
+    pub app_id: AppIdentity,
+    /// The position in the stacking order. The window with the larger value is in front.
+    /// The windows with the same value are ordered by the order they were added.
+    pub z_index: i32,
+    pub focused: bool,
+    pub on_screen: bool,
+    /// The state of the window. [`WindowState::ON_SCREEN`] is ignored and taken from
+    /// [`on_screen`](#structfield.on_screen) instead.
+    pub state: WindowState,
+    pub kind: WindowKind,
+    /// The parent window. The [children](crate::Window::children) of a window are
+    /// the windows whose parent is the window.
+    pub parent: Option<WindowId>,
+    pub owner_window: Option<WindowId>,
+}
+
+impl MockWindow {
+    /// Creates a new window without title, owner and size.
+    pub fn new(id: impl Into<WindowId>) -> Self {
+        Self {
+            id: id.into(),
+            title: None,
+            bounds: Bounds::default(),
+            owner_pid: 0,
+            owner_name: None,
+            app_id: AppIdentity::default(),
+            z_index: 0,
+            focused: false,
+            on_screen: true,
+            state: WindowState::empty(),
+            kind: WindowKind::Normal,
+            parent: None,
+            owner_window: None,
+        }
+    }
+
+    /// Sets the title of the window.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the bounds of the window.
+    pub fn bounds(mut self, bounds: Bounds) -> Self {
+        self.bounds = bounds;
+        self
+    }
+
+    /// Sets the process ID and the name of the window's owner.
+    pub fn owner(mut self, pid: i32, name: impl Into<String>) -> Self {
+        self.owner_pid = pid;
+        self.owner_name = Some(name.into());
+        self
+    }
+
+    /// Sets the identity of the application which owns the window.
+    pub fn app_id(mut self, app_id: AppIdentity) -> Self {
+        self.app_id = app_id;
+        self
+    }
+
+    /// Sets the position in the stacking order. See [`MockWindow::z_index`](#structfield.z_index).
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Sets whether the window has the keyboard focus.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Sets whether the window is on the screen. It is `true` by default.
+    pub fn on_screen(mut self, on_screen: bool) -> Self {
+        self.on_screen = on_screen;
+        self
+    }
+
+    /// Sets the state of the window. See [`MockWindow::state`](#structfield.state).
+    pub fn state(mut self, state: WindowState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Sets the type of the window. It is [`WindowKind::Normal`] by default.
+    pub fn kind(mut self, kind: WindowKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Sets the parent window. See [`MockWindow::parent`](#structfield.parent).
+    pub fn parent(mut self, parent: impl Into<WindowId>) -> Self {
+        self.parent = Some(parent.into());
+        self
+    }
+
+    /// Sets the owner window. It does not change the parent window.
+    pub fn owner_window(mut self, owner: impl Into<WindowId>) -> Self {
+        self.owner_window = Some(owner.into());
+        self
+    }
+}
+
+/// A list of [`MockWindow`]s and [`Monitor`]s which can be mutated over time.
+///
+/// It is cheap to clone, and the clones share the same windows and monitors.
+/// The [`Window`]s retrieved from it read the current state of the windows,
+/// so the mutations are visible through them like real windows.
+#[derive(Clone, Debug, Default)]
+pub struct MockDesktop(Arc<Mutex<MockState>>);
+
+#[derive(Debug, Default)]
+struct MockState {
+    windows: Vec<MockWindow>,
+    monitors: Vec<Monitor>,
+}
+
+impl MockDesktop {
+    /// Creates a new desktop without windows.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the window and returns the desktop. It is useful to declare the initial windows.
+    pub fn with_window(self, window: MockWindow) -> Self {
+        self.add_window(window);
+        self
+    }
+
+    /// Adds the window. If a window with the same ID exists, it is replaced.
+    pub fn add_window(&self, window: MockWindow) {
+        let windows = &mut self.lock().windows;
+
+        match windows.iter_mut().find(|current| current.id == window.id) {
+            Some(current) => *current = window,
+            None => windows.push(window),
+        }
+    }
+
+    /// Removes the window and returns it.
+    pub fn remove_window(&self, id: WindowId) -> Option<MockWindow> {
+        let windows = &mut self.lock().windows;
+        let index = windows.iter().position(|window| window.id == id)?;
+
+        Some(windows.remove(index))
+    }
+
+    /// Mutates the window by `f`. It returns `false` if the window does not exist.
+    pub fn update_window(&self, id: WindowId, f: impl FnOnce(&mut MockWindow)) -> bool {
+        match self
+            .lock()
+            .windows
+            .iter_mut()
+            .find(|window| window.id == id)
+        {
+            Some(window) => {
+                f(window);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the keyboard focus to the window, or removes it from all windows if `None`.
+    pub fn focus(&self, id: Option<WindowId>) {
+        for window in self.lock().windows.iter_mut() {
+            window.focused = Some(window.id) == id;
+        }
+    }
+
+    /// Returns a copy of the window.
+    pub fn window(&self, id: WindowId) -> Option<MockWindow> {
+        self.lock()
+            .windows
+            .iter()
+            .find(|window| window.id == id)
+            .cloned()
+    }
+
+    /// Returns a copy of the windows in front-to-back order.
+    pub fn windows(&self) -> Vec<MockWindow> {
+        let mut windows = self.lock().windows.clone();
+        windows.sort_by_key(|window| std::cmp::Reverse(window.z_index));
+        windows
+    }
+
+    /// Adds the monitor and returns the desktop. It is useful to declare the initial monitors.
+    pub fn with_monitor(self, monitor: Monitor) -> Self {
+        self.lock().monitors.push(monitor);
+        self
+    }
+
+    /// Replaces the monitors.
+    pub fn set_monitors(&self, monitors: Vec<Monitor>) {
+        self.lock().monitors = monitors;
+    }
+
+    /// Returns a copy of the monitors.
+    pub fn monitors(&self) -> Vec<Monitor> {
+        self.lock().monitors.clone()
+    }
+
+    /// Installs the desktop on the current thread.
+    ///
+    /// Until the returned guard is dropped, [`get_windows`](crate::get_windows) and
+    /// [`get_window`](crate::get_window) called on the current thread read from this desktop.
+    /// It is installed per thread so the tests running in parallel do not interfere.
+    pub fn install(&self) -> MockGuard {
+        let previous = INSTALLED.with(|installed| installed.replace(Some(self.clone())));
+
+        MockGuard { previous }
+    }
+
+    fn window_handle(&self, id: WindowId) -> Window {
+        Window::from_backend(MockWindowHandle {
+            desktop: self.clone(),
+            id,
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl WindowBackend for MockDesktop {
+    fn get_windows(&self) -> Result<Vec<Window>, Error> {
+        Ok(self
+            .windows()
+            .into_iter()
+            .map(|window| self.window_handle(window.id))
+            .collect())
+    }
+
+    fn get_window(&self, id: WindowId) -> Result<Option<Window>, Error> {
+        Ok(self.window(id).map(|window| self.window_handle(window.id)))
+    }
+
+    fn get_monitors(&self) -> Result<Vec<Monitor>, Error> {
+        Ok(self.monitors())
+    }
+}
+
+/// The guard returned by [`MockDesktop::install`].
+/// It restores the previously installed desktop when dropped.
+#[derive(Debug)]
+#[must_use = "the desktop is uninstalled when the guard is dropped"]
+pub struct MockGuard {
+    previous: Option<MockDesktop>,
+}
+
+impl Drop for MockGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        INSTALLED.with(|installed| *installed.borrow_mut() = previous);
+    }
+}
+
+/// A window retrieved from a [`MockDesktop`], which reads the current state of the window.
+#[derive(Debug)]
+struct MockWindowHandle {
+    desktop: MockDesktop,
+    id: WindowId,
+}
+
+impl MockWindowHandle {
+    fn read<R>(&self, f: impl FnOnce(&MockWindow) -> R) -> Result<R, Error> {
+        self.desktop
+            .lock()
+            .windows
+            .iter()
+            .find(|window| window.id == self.id)
+            .map(f)
+            .ok_or(Error::WindowNotFound(self.id))
+    }
+}
+
+impl BackendWindow for MockWindowHandle {
+    fn id(&self) -> WindowId {
+        self.id
+    }
+
+    fn title(&self) -> Result<Option<String>, Error> {
+        self.read(|window| window.title.clone())
+    }
+
+    fn bounds(&self) -> Result<Bounds, Error> {
+        self.read(|window| window.bounds.clone())
+    }
+
+    fn owner_pid(&self) -> Result<i32, Error> {
+        self.read(|window| window.owner_pid)
+    }
+
+    fn owner_name(&self) -> Result<Option<String>, Error> {
+        self.read(|window| window.owner_name.clone())
+    }
+
+    /// The process which only has the process ID and the name of the owner.
+    fn owner_process(&self) -> Result<ProcessInfo, Error> {
+        self.read(|window| ProcessInfo {
+            name: window.owner_name.clone(),
+            ..ProcessInfo::new(window.owner_pid)
+        })
+    }
+
+    fn app_id(&self) -> Result<AppIdentity, Error> {
+        self.read(|window| window.app_id.clone())
+    }
+
+    fn is_focused(&self) -> Result<bool, Error> {
+        self.read(|window| window.focused)
+    }
+
+    fn is_on_screen(&self) -> Result<bool, Error> {
+        self.read(|window| window.on_screen)
+    }
+
+    fn kind(&self) -> Result<WindowKind, Error> {
+        self.read(|window| window.kind)
+    }
+
+    fn parent(&self) -> Result<Option<WindowId>, Error> {
+        self.read(|window| window.parent)
+    }
+
+    fn owner_window(&self) -> Result<Option<WindowId>, Error> {
+        self.read(|window| window.owner_window)
+    }
+
+    /// The windows whose parent is the window, in the order they were added.
+    fn children(&self) -> Result<Vec<WindowId>, Error> {
+        self.read(|_| ())?;
+
+        Ok(self
+            .desktop
+            .lock()
+            .windows
+            .iter()
+            .filter(|window| window.parent == Some(self.id))
+            .map(|window| window.id)
+            .collect())
+    }
+
+    fn state(&self) -> Result<WindowState, Error> {
+        self.read(|window| {
+            let mut state = window.state;
+            state.set(WindowState::ON_SCREEN, window.on_screen);
+            state
+        })
+    }
+
+    /// The monitor which has the largest overlap with the window.
+    fn monitor(&self) -> Result<Option<Monitor>, Error> {
+        let bounds = self.bounds()?;
+
+        Ok(crate::monitor::monitor_for_bounds(
+            self.desktop.monitors(),
+            &bounds,
+        ))
+    }
+}
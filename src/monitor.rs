@@ -0,0 +1,103 @@
+use crate::Bounds;
+
+/// The unit of the coordinates of [`Bounds`].
+///
+/// The coordinates are relative to the origin of the whole desktop in both spaces,
+/// e.g. the top-left corner of the primary monitor on Windows and macOS or the root window
+/// on X11. When converting between them, the top-left corner of each monitor stays
+/// at the same position and the distances from it are scaled by its
+/// [scale factor](Monitor::scale_factor).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CoordinateSpace {
+    /// The physical pixels of the monitors.
+    Physical,
+    /// The logical points, which are the physical pixels divided by the scale factor.
+    Logical,
+}
+
+/// A display connected to the system.
+///
+/// The bounds are in the same coordinate space as the [bounds](crate::Window::bounds) of
+/// the windows, so a window can be located on the monitors by comparing them.
+/// The space is told by [`coordinate_space`](Self::coordinate_space).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Monitor {
+    /// The identifier of the monitor. It is unique among the monitors of a [`get_monitors`](crate::get_monitors)
+    /// call, but it may change when the monitors are reconfigured.
+    ///
+    /// # Platform-specific
+    /// - **Windows**: It is the `HMONITOR`.
+    /// - **macOS**: It is the `CGDirectDisplayID`.
+    /// - **Linux (X11)**: It is the atom of the RandR monitor name.
+    /// - **Linux (Wayland)**: It is the name of the `wl_output` global.
+    pub id: u64,
+    /// The name of the monitor, e.g. the name of the connector such as `DP-1`.
+    pub name: Option<String>,
+    pub bounds: Bounds,
+    /// The part of the bounds which is not reserved by the taskbar, the dock or the panels.
+    /// It is the same as the bounds if the platform does not provide it.
+    pub work_area: Bounds,
+    /// The ratio of the physical pixels to the logical pixels, e.g. `2.0` on a Retina display.
+    pub scale_factor: f64,
+    pub is_primary: bool,
+    /// The space of [`bounds`](Self::bounds) and [`work_area`](Self::work_area),
+    /// which is also the one of the bounds of the windows.
+    ///
+    /// # Platform-specific
+    /// - **Windows**: It is [`Physical`](CoordinateSpace::Physical). The bounds of the
+    ///   monitors are only physical when the process is per-monitor DPI aware.
+    /// - **macOS**: It is [`Logical`](CoordinateSpace::Logical).
+    /// - **Linux (X11)**: It is [`Physical`](CoordinateSpace::Physical).
+    /// - **Linux (Wayland)**: It is [`Logical`](CoordinateSpace::Logical).
+    pub coordinate_space: CoordinateSpace,
+}
+
+impl Monitor {
+    /// Returns the bounds of the monitor in the space.
+    pub fn bounds_in(&self, space: CoordinateSpace) -> Bounds {
+        self.convert(&self.bounds, space)
+    }
+
+    /// Returns the work area of the monitor in the space.
+    pub fn work_area_in(&self, space: CoordinateSpace) -> Bounds {
+        self.convert(&self.work_area, space)
+    }
+
+    /// Converts the bounds on this monitor from [`coordinate_space`](Self::coordinate_space)
+    /// to the space.
+    pub fn convert(&self, bounds: &Bounds, space: CoordinateSpace) -> Bounds {
+        let scale = match (self.coordinate_space, space) {
+            (CoordinateSpace::Logical, CoordinateSpace::Physical) => self.scale_factor,
+            (CoordinateSpace::Physical, CoordinateSpace::Logical) => 1.0 / self.scale_factor,
+            _ => return bounds.clone(),
+        };
+
+        Bounds::new(
+            self.bounds.x + (bounds.x - self.bounds.x) * scale,
+            self.bounds.y + (bounds.y - self.bounds.y) * scale,
+            bounds.width * scale,
+            bounds.height * scale,
+        )
+    }
+}
+
+/// Returns the monitor which has the largest overlap with the bounds.
+/// It is `None` if the bounds are not on any monitor.
+pub(crate) fn monitor_for_bounds(monitors: Vec<Monitor>, bounds: &Bounds) -> Option<Monitor> {
+    monitors
+        .into_iter()
+        .filter_map(|monitor| {
+            let area = monitor.bounds.intersect(bounds)?.area();
+            Some((monitor, area))
+        })
+        .fold(
+            None,
+            |largest: Option<(Monitor, f64)>, (monitor, area)| match largest {
+                Some(largest) if largest.1 >= area => Some(largest),
+                _ => Some((monitor, area)),
+            },
+        )
+        .map(|(monitor, _)| monitor)
+}
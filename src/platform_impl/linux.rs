@@ -0,0 +1,663 @@
+use std::{
+    fs, io,
+    os::fd::{AsFd, BorrowedFd},
+    path::Path,
+    sync::OnceLock,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    AppIdentity, BackendWindow, Bounds, Error, Monitor, ProcessInfo, Window, WindowBackend,
+    WindowId, WindowKind, WindowQuery, WindowSnapshot, WindowState,
+};
+
+use super::{
+    wayland::{self, WaylandError, WaylandNotifier, WaylandProtocol, WaylandWindow},
+    x11::{self, X11Bounds, X11Error, X11Notifier, X11Window},
+};
+
+pub type LinuxBounds = X11Bounds;
+pub type LinuxWindowId = u32;
+
+/// The sources which windows can be retrieved from on Linux.
+///
+/// The IPC of the compositors such as sway and Hyprland is not a backend yet,
+/// so their windows are retrieved through the Wayland protocols.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Backend {
+    /// The X server specified by `DISPLAY`.
+    /// On Wayland sessions, it only sees the XWayland clients.
+    X11,
+    /// The Wayland compositor specified by `WAYLAND_DISPLAY` with the given protocol.
+    Wayland(WaylandProtocol),
+}
+
+impl WindowBackend for Backend {
+    fn get_windows(&self) -> Result<Vec<Window>, Error> {
+        get_windows_with(*self)
+    }
+
+    fn get_window(&self, id: WindowId) -> Result<Option<Window>, Error> {
+        get_window_with(*id.platform_window_id(), *self)
+    }
+
+    fn get_foreground_window(&self) -> Result<Option<Window>, Error> {
+        get_foreground_window_with(*self)
+    }
+
+    fn find_windows(&self, query: &WindowQuery) -> Result<Vec<Window>, Error> {
+        find_windows_with(query, *self)
+    }
+
+    fn get_monitors(&self) -> Result<Vec<Monitor>, Error> {
+        get_monitors_with(*self)
+    }
+
+    fn find_window_at(&self, query: &WindowQuery, x: f64, y: f64) -> Result<Option<Window>, Error> {
+        find_window_at_with(query, x, y, *self)
+    }
+}
+
+/// The values of `XDG_CURRENT_DESKTOP` of the wlroots-based compositors, which
+/// provide more information through `zwlr_foreign_toplevel_manager_v1`
+/// than `ext_foreign_toplevel_list_v1`.
+const WLROOTS_DESKTOPS: [&str; 5] = ["sway", "river", "labwc", "Hyprland", "Wayfire"];
+
+/// Detects the backend to use from the environment.
+///
+/// If `WAYLAND_DISPLAY` is set and the compositor supports one of the foreign toplevel
+/// protocols, the Wayland backend is used. `zwlr_foreign_toplevel_manager_v1` is preferred
+/// when `XDG_CURRENT_DESKTOP` names a wlroots-based compositor, otherwise
+/// `ext_foreign_toplevel_list_v1` is preferred. Then if `DISPLAY` is set,
+/// the X11 backend is used.
+///
+/// It returns [`Error::NoBackendAvailable`] when none of them is usable.
+///
+/// The detected backend is kept for the whole process, so the compositor is only asked
+/// for its protocols once. The detection is tried again while it fails.
+pub fn detect_backend() -> Result<Backend, Error> {
+    static DETECTED: OnceLock<Backend> = OnceLock::new();
+
+    if let Some(&backend) = DETECTED.get() {
+        return Ok(backend);
+    }
+
+    let backend = detect_backend_uncached()?;
+    Ok(*DETECTED.get_or_init(|| backend))
+}
+
+fn detect_backend_uncached() -> Result<Backend, Error> {
+    if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        match wayland::supported_protocols() {
+            Ok(protocols) => {
+                let prefers_wlr = std::env::var("XDG_CURRENT_DESKTOP").is_ok_and(|desktops| {
+                    desktops
+                        .split(':')
+                        .any(|desktop| WLROOTS_DESKTOPS.contains(&desktop))
+                });
+                let preferred = if prefers_wlr {
+                    WaylandProtocol::WlrForeignToplevelManagement
+                } else {
+                    WaylandProtocol::ExtForeignToplevelList
+                };
+
+                let protocol = if protocols.contains(&preferred) {
+                    preferred
+                } else {
+                    protocols[0]
+                };
+
+                return Ok(Backend::Wayland(protocol));
+            }
+            Err(error) if is_wayland_unavailable(&error) => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    if std::env::var_os("DISPLAY").is_some() {
+        return Ok(Backend::X11);
+    }
+
+    Err(Error::NoBackendAvailable)
+}
+
+/// Retrieves a window by its unique identifier from the backend
+/// detected by [`detect_backend`].
+pub fn get_window(id: LinuxWindowId) -> Result<Option<Window>, Error> {
+    get_window_with(id, detect_backend()?)
+}
+
+/// Retrieves a window by its unique identifier from the given backend.
+pub fn get_window_with(id: LinuxWindowId, backend: Backend) -> Result<Option<Window>, Error> {
+    match backend {
+        Backend::X11 => x11::get_window(id),
+        Backend::Wayland(protocol) => wayland::get_window_with(id, Some(protocol)),
+    }
+}
+
+/// Retrieves the window which has the keyboard focus from the backend
+/// detected by [`detect_backend`].
+pub fn get_foreground_window() -> Result<Option<Window>, Error> {
+    get_foreground_window_with(detect_backend()?)
+}
+
+/// Retrieves the window which has the keyboard focus from the given backend.
+///
+/// On X11, it is `_NET_ACTIVE_WINDOW`. On Wayland, it is the activated window of
+/// `zwlr_foreign_toplevel_manager_v1`, and it is unsupported on `ext_foreign_toplevel_list_v1`.
+pub fn get_foreground_window_with(backend: Backend) -> Result<Option<Window>, Error> {
+    match backend {
+        Backend::X11 => x11::get_foreground_window(),
+        Backend::Wayland(protocol) => wayland::get_foreground_window_with(Some(protocol)),
+    }
+}
+
+/// Retrieves a list of all open windows from the backend detected by [`detect_backend`].
+pub fn get_windows() -> Result<Vec<Window>, Error> {
+    get_windows_with(detect_backend()?)
+}
+
+/// Retrieves a list of all open windows from the given backend.
+pub fn get_windows_with(backend: Backend) -> Result<Vec<Window>, Error> {
+    match backend {
+        Backend::X11 => x11::get_windows(),
+        Backend::Wayland(protocol) => wayland::get_windows_with(Some(protocol)),
+    }
+}
+
+/// Retrieves the monitors from the backend detected by [`detect_backend`].
+pub fn get_monitors() -> Result<Vec<Monitor>, Error> {
+    get_monitors_with(detect_backend()?)
+}
+
+/// Retrieves the monitors from the given backend.
+///
+/// On X11, they are the RandR monitors. On Wayland, they are the `wl_output`s.
+pub fn get_monitors_with(backend: Backend) -> Result<Vec<Monitor>, Error> {
+    match backend {
+        Backend::X11 => x11::get_monitors(),
+        Backend::Wayland(_) => wayland::get_monitors(),
+    }
+}
+
+/// Retrieves the windows matching the query from the backend detected by [`detect_backend`].
+pub fn find_windows(query: &WindowQuery) -> Result<Vec<Window>, Error> {
+    find_windows_with(query, detect_backend()?)
+}
+
+/// Retrieves the windows matching the query from the given backend.
+///
+/// On X11, the conditions on the owner's process ID are checked first by
+/// [`x11::find_windows`].
+pub fn find_windows_with(query: &WindowQuery, backend: Backend) -> Result<Vec<Window>, Error> {
+    match backend {
+        Backend::X11 => x11::find_windows(query),
+        Backend::Wayland(protocol) => {
+            let windows = wayland::get_windows_with(Some(protocol))?;
+
+            Ok(query.take_matches(windows, |window| query.matches(window)))
+        }
+    }
+}
+
+/// Retrieves the frontmost window matching the query at the point from the backend detected
+/// by [`detect_backend`].
+pub fn find_window_at(query: &WindowQuery, x: f64, y: f64) -> Result<Option<Window>, Error> {
+    find_window_at_with(query, x, y, detect_backend()?)
+}
+
+/// Retrieves the frontmost window matching the query at the point from the given backend.
+///
+/// On X11, it is [`x11::find_window_at`]. On Wayland, it is unsupported because
+/// the bounds of the windows are not exposed.
+pub fn find_window_at_with(
+    query: &WindowQuery,
+    x: f64,
+    y: f64,
+    backend: Backend,
+) -> Result<Option<Window>, Error> {
+    match backend {
+        Backend::X11 => x11::find_window_at(query, x, y),
+        Backend::Wayland(_) => Err(Error::Unsupported("get_window_at")),
+    }
+}
+
+/// Returns the parent of the Wayland window, which is only provided by
+/// `zwlr_foreign_toplevel_manager_v1`. `method` is the name reported as unsupported.
+fn wayland_parent(window: &WaylandWindow, method: &'static str) -> Result<Option<u32>, Error> {
+    if window.state().is_none() {
+        return Err(Error::Unsupported(method));
+    }
+
+    Ok(window.parent())
+}
+
+fn is_wayland_unavailable(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::PlatformSpecificError(LinuxError::Wayland(error)) if error.is_unavailable()
+    )
+}
+
+/// Reads the information of the process from procfs mounted at `proc_root`,
+/// which is usually `/proc`.
+///
+/// The name, the parent process ID and the start time are read from `<pid>/stat`, which is
+/// required. The executable, the command line and the real user ID are read from
+/// `<pid>/exe`, `<pid>/cmdline` and `<pid>/status`, and they are `None` if they cannot be read,
+/// e.g. `<pid>/exe` of the processes of the other users. The start time also requires
+/// the boot time in `stat` of the root.
+pub fn read_process_info(proc_root: impl AsRef<Path>, pid: u32) -> Result<ProcessInfo, Error> {
+    let root = proc_root.as_ref();
+    let directory = root.join(pid.to_string());
+
+    let stat = fs::read_to_string(directory.join("stat")).map_err(io_error)?;
+    let stat = ProcStat::parse(&stat).ok_or_else(|| {
+        io_error(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("malformed `/proc/{pid}/stat`"),
+        ))
+    })?;
+
+    let command_line = fs::read(directory.join("cmdline")).ok().map(|cmdline| {
+        // Each argument is terminated by a null byte, so only the last one is removed
+        // to keep the empty arguments. It is empty for the kernel threads.
+        let cmdline = cmdline.strip_suffix(&[0]).unwrap_or(&cmdline);
+        if cmdline.is_empty() {
+            return Vec::new();
+        }
+
+        cmdline
+            .split(|&byte| byte == 0)
+            .map(|argument| String::from_utf8_lossy(argument).into_owned())
+            .collect()
+    });
+    let user_id = fs::read_to_string(directory.join("status"))
+        .ok()
+        .and_then(|status| {
+            let uid = status.lines().find_map(|line| line.strip_prefix("Uid:"))?;
+            uid.split_whitespace().next()?.parse().ok()
+        });
+    let start_time = fs::read_to_string(root.join("stat"))
+        .ok()
+        .and_then(|system_stat| {
+            let boot_time = system_stat
+                .lines()
+                .find_map(|line| line.strip_prefix("btime "))?;
+            let boot_time = Duration::from_secs(boot_time.trim().parse().ok()?);
+            let ticks = rustix::param::clock_ticks_per_second();
+            let uptime = Duration::from_secs(stat.start_ticks / ticks)
+                + Duration::from_nanos(stat.start_ticks % ticks * 1_000_000_000 / ticks);
+
+            Some(SystemTime::UNIX_EPOCH + boot_time + uptime)
+        });
+
+    Ok(ProcessInfo {
+        pid: pid as _,
+        name: Some(stat.name),
+        executable: fs::read_link(directory.join("exe")).ok(),
+        command_line,
+        parent_pid: Some(stat.parent_pid),
+        user_id,
+        start_time,
+    })
+}
+
+/// The fields of `/proc/<pid>/stat` used by [`read_process_info`].
+struct ProcStat {
+    name: String,
+    parent_pid: i32,
+    /// The time the process started after the system boot in clock ticks.
+    start_ticks: u64,
+}
+
+impl ProcStat {
+    fn parse(stat: &str) -> Option<Self> {
+        // The name is enclosed in parentheses and may contain spaces and parentheses,
+        // so the fields are split after the last `)`.
+        let (head, tail) = stat.rsplit_once(')')?;
+        let (_, name) = head.split_once('(')?;
+        // The fields after the name start from the third field, the state.
+        let fields = tail.split_whitespace().collect::<Vec<_>>();
+
+        Some(Self {
+            name: name.to_owned(),
+            parent_pid: fields.get(1)?.parse().ok()?,
+            start_ticks: fields.get(19)?.parse().ok()?,
+        })
+    }
+}
+
+fn io_error(error: io::Error) -> Error {
+    if error.kind() == io::ErrorKind::PermissionDenied {
+        Error::PermissionDenied(LinuxError::Io(error))
+    } else {
+        Error::PlatformSpecificError(LinuxError::Io(error))
+    }
+}
+
+/// Wakes up [`WindowWatcher`](crate::WindowWatcher) when the windows of a backend
+/// may have changed.
+pub(crate) enum LinuxNotifier {
+    X11(X11Notifier),
+    Wayland(WaylandNotifier),
+}
+
+impl LinuxNotifier {
+    pub fn new(backend: Backend) -> Result<Self, Error> {
+        match backend {
+            Backend::X11 => Ok(Self::X11(X11Notifier::new()?)),
+            Backend::Wayland(_) => Ok(Self::Wayland(WaylandNotifier::new()?)),
+        }
+    }
+
+    /// Updates the windows to receive the notifications of.
+    pub fn watch(&mut self, windows: &[WindowId]) -> Result<(), Error> {
+        match self {
+            Self::X11(notifier) => {
+                let windows = windows.iter().map(WindowId::as_u32).collect::<Vec<_>>();
+                Ok(notifier.watch(&windows)?)
+            }
+            Self::Wayland(_) => Ok(()),
+        }
+    }
+
+    /// Reads the notifications received so far without blocking and returns `true`
+    /// if there were any.
+    ///
+    /// On Wayland, the events are read by the session when the windows are checked,
+    /// so it always returns `true`.
+    #[cfg(feature = "tokio")]
+    pub fn take_notifications(&mut self) -> Result<bool, Error> {
+        match self {
+            Self::X11(notifier) => Ok(notifier.drain()?),
+            Self::Wayland(_) => Ok(true),
+        }
+    }
+
+    /// Waits until a notification is received or the timeout elapses.
+    pub fn wait(&mut self, timeout: Duration) -> Result<(), Error> {
+        match self {
+            Self::X11(notifier) => Ok(notifier.wait(timeout)?),
+            Self::Wayland(notifier) => {
+                notifier.wait(timeout);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl AsFd for LinuxNotifier {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        match self {
+            Self::X11(notifier) => notifier.as_fd(),
+            Self::Wayland(notifier) => notifier.as_fd(),
+        }
+    }
+}
+
+/// Waits until the file descriptor becomes readable or the timeout elapses.
+///
+/// The errors of `poll` are ignored because they are reported again when
+/// the connection is read.
+pub(crate) fn wait_readable(fd: BorrowedFd<'_>, timeout: Duration) {
+    use rustix::event::{PollFd, PollFlags, Timespec};
+
+    let mut fds = [PollFd::from_borrowed_fd(fd, PollFlags::IN)];
+    let timeout = Timespec::try_from(timeout).ok();
+
+    let _ = rustix::event::poll(&mut fds, timeout.as_ref());
+}
+
+/// Represents a window retrieved from one of the Linux backends.
+#[derive(Clone, Debug)]
+pub enum LinuxWindow {
+    /// A window on the X server.
+    X11(X11Window),
+    /// A toplevel window announced by the Wayland compositor.
+    Wayland(WaylandWindow),
+}
+
+impl LinuxWindow {
+    /// Returns the window unique identifier.
+    pub fn id(&self) -> LinuxWindowId {
+        match self {
+            Self::X11(window) => window.id(),
+            Self::Wayland(window) => window.id(),
+        }
+    }
+
+    /// Returns the title of the window.
+    pub fn title(&self) -> Result<Option<String>, Error> {
+        match self {
+            Self::X11(window) => Ok(window.title()?),
+            Self::Wayland(window) => Ok(window.title().map(ToOwned::to_owned)),
+        }
+    }
+
+    /// Returns the bounds of the window.
+    /// Wayland does not expose the position of windows, so it is unsupported on Wayland.
+    pub fn bounds(&self) -> Result<Bounds, Error> {
+        match self {
+            Self::X11(window) => Ok(window.bounds()?),
+            Self::Wayland(_) => Err(Error::Unsupported("bounds")),
+        }
+    }
+
+    /// Returns the process ID of the window's owner.
+    /// Wayland does not expose the client of windows, so it is unsupported on Wayland.
+    pub fn owner_pid(&self) -> Result<u32, Error> {
+        match self {
+            Self::X11(window) => Ok(window.owner_pid()?),
+            Self::Wayland(_) => Err(Error::Unsupported("owner_pid")),
+        }
+    }
+
+    /// Returns the name of the process that owns the window.
+    /// It is unsupported on Wayland, like [`owner_pid`](Self::owner_pid).
+    pub fn owner_name(&self) -> Result<Option<String>, Error> {
+        match self {
+            Self::X11(window) => Ok(window.owner_name()?),
+            Self::Wayland(_) => Err(Error::Unsupported("owner_name")),
+        }
+    }
+
+    /// Returns the information of the process that owns the window from `/proc`.
+    /// See [`read_process_info`].
+    ///
+    /// On X11, only the process ID is provided if the owner is not running on this machine.
+    /// See [`X11Window::local_owner_pid`].
+    pub fn owner_process(&self) -> Result<ProcessInfo, Error> {
+        match self {
+            Self::X11(window) => match window.local_owner_pid()? {
+                Some(pid) => read_process_info("/proc", pid),
+                None => Ok(ProcessInfo::new(window.owner_pid()? as _)),
+            },
+            Self::Wayland(_) => Err(Error::Unsupported("owner_process")),
+        }
+    }
+
+    /// Reads all the properties at once.
+    /// On X11, the requests are answered in a single round trip by [`X11Window::properties`].
+    pub fn snapshot(&self) -> WindowSnapshot {
+        let Self::X11(window) = self else {
+            return WindowSnapshot::from_results(
+                BackendWindow::id(self),
+                self.title(),
+                self.bounds(),
+                self.owner_pid().map(|pid| pid as _),
+                self.owner_name(),
+            );
+        };
+
+        let id = BackendWindow::id(self);
+        match window.properties() {
+            Ok(properties) => WindowSnapshot::from_results(
+                id,
+                properties.title.map_err(Into::into),
+                properties.bounds.map_err(Into::into),
+                properties.owner_pid.map(|pid| pid as _).map_err(Into::into),
+                properties.owner_name.map_err(Into::into),
+            ),
+            Err(error) => WindowSnapshot::from_error(id, error.into()),
+        }
+    }
+
+    /// Checks if the window is on the screen.
+    ///
+    /// On X11, it checks if the window is viewable. On Wayland, it checks if the window
+    /// is not minimized, which is only supported with `zwlr_foreign_toplevel_manager_v1`.
+    pub fn is_on_screen(&self) -> Result<bool, Error> {
+        match self {
+            Self::X11(window) => Ok(window.is_viewable()?),
+            Self::Wayland(window) => window
+                .state()
+                .map(|state| !state.minimized)
+                .ok_or(Error::Unsupported("is_on_screen")),
+        }
+    }
+
+    /// Returns the state of the window.
+    ///
+    /// On X11, it is read by [`X11Window::state`]. On Wayland, it is only supported with
+    /// `zwlr_foreign_toplevel_manager_v1`.
+    pub fn state(&self) -> Result<WindowState, Error> {
+        match self {
+            Self::X11(window) => Ok(window.state()?),
+            Self::Wayland(window) => {
+                let state = window.state().ok_or(Error::Unsupported("state"))?;
+
+                let mut flags = WindowState::empty();
+                flags.set(WindowState::MINIMIZED, state.minimized);
+                flags.set(WindowState::MAXIMIZED, state.maximized);
+                flags.set(WindowState::FULLSCREEN, state.fullscreen);
+                flags.set(WindowState::ON_SCREEN, !state.minimized);
+                Ok(flags)
+            }
+        }
+    }
+
+    /// Returns the identity of the application which owns the window.
+    ///
+    /// On X11, it is read from `WM_CLASS` and the owner's process ID.
+    /// On Wayland, only the application ID is provided.
+    pub fn app_id(&self) -> Result<AppIdentity, Error> {
+        match self {
+            Self::X11(window) => Ok(window.app_id()?),
+            Self::Wayland(window) => Ok(AppIdentity {
+                id: window.app_id().map(ToOwned::to_owned),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Returns the type of the window.
+    ///
+    /// On X11, it is read from `_NET_WM_WINDOW_TYPE`. On Wayland, the window is a dialog
+    /// if it has a [parent](Self::parent) and a normal window otherwise, so it is unsupported
+    /// where the parent is.
+    pub fn kind(&self) -> Result<WindowKind, Error> {
+        match self {
+            Self::X11(window) => Ok(window.kind()?),
+            Self::Wayland(window) => Ok(if wayland_parent(window, "kind")?.is_some() {
+                WindowKind::Dialog
+            } else {
+                WindowKind::Normal
+            }),
+        }
+    }
+
+    /// Returns the ID of the parent window.
+    ///
+    /// On X11, it is `WM_TRANSIENT_FOR`, or the parent by `XQueryTree` for the windows
+    /// which are not top-level. On Wayland, it is the parent of
+    /// `zwlr_foreign_toplevel_manager_v1`.
+    pub fn parent(&self) -> Result<Option<u32>, Error> {
+        match self {
+            Self::X11(window) => Ok(window.parent()?),
+            Self::Wayland(window) => wayland_parent(window, "parent"),
+        }
+    }
+
+    /// Returns the ID of the owner window.
+    ///
+    /// On X11, it is `WM_TRANSIENT_FOR`. On Wayland, it is the same as [`parent`](Self::parent).
+    pub fn owner_window(&self) -> Result<Option<u32>, Error> {
+        match self {
+            Self::X11(window) => Ok(window.owner_window()?),
+            Self::Wayland(window) => wayland_parent(window, "owner_window"),
+        }
+    }
+
+    /// Returns the IDs of the windows whose [parent](Self::parent) is the window.
+    pub fn children(&self) -> Result<Vec<u32>, Error> {
+        match self {
+            Self::X11(window) => Ok(window.children()?),
+            Self::Wayland(window) => {
+                wayland_parent(window, "children")?;
+
+                let windows =
+                    wayland::get_windows_with(Some(WaylandProtocol::WlrForeignToplevelManagement))?;
+
+                Ok(windows
+                    .into_iter()
+                    .filter_map(|child| match child.into_platform_window()? {
+                        Self::Wayland(child) => Some(child),
+                        Self::X11(_) => None,
+                    })
+                    .filter(|child| child.parent() == Some(window.id()))
+                    .map(|child| child.id())
+                    .collect())
+            }
+        }
+    }
+
+    /// Returns the monitor on which the window is.
+    ///
+    /// On X11, it is the monitor which has the largest overlap with the window.
+    /// On Wayland, it is the first output the window entered, which is only supported
+    /// with `zwlr_foreign_toplevel_manager_v1`.
+    pub fn monitor(&self) -> Result<Option<Monitor>, Error> {
+        match self {
+            Self::X11(window) => Ok(window.monitor()?),
+            Self::Wayland(window) => {
+                if window.state().is_none() {
+                    return Err(Error::Unsupported("monitor"));
+                }
+                let Some(&output) = window.outputs().first() else {
+                    return Ok(None);
+                };
+
+                Ok(wayland::get_monitors()?
+                    .into_iter()
+                    .find(|monitor| monitor.id == u64::from(output)))
+            }
+        }
+    }
+
+    /// Checks if the window has the keyboard focus.
+    ///
+    /// On X11, it checks if the window is `_NET_ACTIVE_WINDOW`. On Wayland, it is only
+    /// supported with `zwlr_foreign_toplevel_manager_v1`.
+    pub fn is_focused(&self) -> Result<bool, Error> {
+        match self {
+            Self::X11(window) => Ok(window.is_active()?),
+            Self::Wayland(window) => window
+                .is_activated()
+                .ok_or(Error::Unsupported("is_focused")),
+        }
+    }
+}
+
+/// Low-level errors that can occur on one of the Linux backends.
+#[derive(Debug, thiserror::Error)]
+pub enum LinuxError {
+    #[error(transparent)]
+    X11(#[from] X11Error),
+    #[error(transparent)]
+    Wayland(#[from] WaylandError),
+    /// Represents a failure to read the information of a process from procfs.
+    #[error("Failed to read the process information: {0}")]
+    Io(#[from] std::io::Error),
+}
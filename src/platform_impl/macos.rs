@@ -1,7 +1,10 @@
 use objc2_core_foundation::{CFArray, CFDictionary, CFRetained, CFString, CFType};
-use objc2_core_graphics::{CGWindowListCopyWindowInfo, CGWindowListOption, kCGNullWindowID};
+use objc2_core_graphics::{
+    CGDisplayBounds, CGDisplayCopyDisplayMode, CGDisplayIsMain, CGDisplayMode, CGError,
+    CGGetActiveDisplayList, CGWindowListCopyWindowInfo, CGWindowListOption, kCGNullWindowID,
+};
 
-use crate::{Error, Window};
+use crate::{Bounds, CoordinateSpace, Error, Monitor, Window};
 
 pub use error::MacOSError;
 pub use window::MacOSWindow;
@@ -24,7 +27,7 @@ pub fn get_window(id: MacOSWindowId) -> Result<Option<Window>, Error> {
     for dict in list.iter() {
         let window = MacOSWindow::new(WindowInfo::new(dict));
         if window.id() == id {
-            return Ok(Some(Window(window)));
+            return Ok(Some(Window::new(window)));
         }
     }
 
@@ -44,19 +47,84 @@ pub fn get_windows() -> Result<Vec<Window>, Error> {
 
     let windows = list
         .iter()
-        .map(|dict| Window(MacOSWindow::new(WindowInfo::new(dict))))
+        .map(|dict| Window::new(MacOSWindow::new(WindowInfo::new(dict))))
         .collect();
 
     Ok(windows)
 }
 
+/// Retrieves the window which is assumed to have the keyboard focus.
+///
+/// macOS does not expose the focused window without the accessibility permission,
+/// so it is the frontmost window on the screen in the normal window layer, which is
+/// usually the key window of the active application.
+pub fn get_foreground_window() -> Result<Option<Window>, Error> {
+    let window = get_windows()?.into_iter().find(|window| {
+        window
+            .platform_window()
+            .is_some_and(|window| window.layer() == 0 && window.is_on_screen())
+    });
+
+    Ok(window)
+}
+
+/// Retrieves the active displays by `CGGetActiveDisplayList`.
+///
+/// The work area is the same as the bounds because the areas of the menu bar and the Dock
+/// are only provided by `NSScreen` of AppKit.
+pub fn get_monitors() -> Result<Vec<Monitor>, Error> {
+    let mut count = 0;
+    if unsafe { CGGetActiveDisplayList(0, std::ptr::null_mut(), &mut count) } != CGError::Success {
+        return Err(Error::NoWindowEnvironment);
+    }
+
+    let mut displays = vec![0; count as usize];
+    if unsafe { CGGetActiveDisplayList(count, displays.as_mut_ptr(), &mut count) }
+        != CGError::Success
+    {
+        return Err(Error::NoWindowEnvironment);
+    }
+    displays.truncate(count as usize);
+
+    let monitors = displays
+        .into_iter()
+        .map(|display| {
+            let bounds = Bounds::from(CGDisplayBounds(display));
+            let scale_factor = CGDisplayCopyDisplayMode(display).map_or(1.0, |mode| {
+                match CGDisplayMode::width(Some(&mode)) {
+                    0 => 1.0,
+                    width => CGDisplayMode::pixel_width(Some(&mode)) as f64 / width as f64,
+                }
+            });
+
+            Monitor {
+                id: display.into(),
+                name: None,
+                work_area: bounds.clone(),
+                bounds,
+                scale_factor,
+                is_primary: CGDisplayIsMain(display),
+                coordinate_space: CoordinateSpace::Logical,
+            }
+        })
+        .collect();
+
+    Ok(monitors)
+}
+
 pub mod window {
-    use std::mem::MaybeUninit;
+    use std::{
+        ffi::{OsString, c_void},
+        mem::MaybeUninit,
+        os::unix::ffi::OsStringExt,
+        path::{Path, PathBuf},
+        time::{Duration, SystemTime},
+    };
 
-    use objc2_core_foundation::CGRect;
+    use objc2_core_foundation::{CFBundle, CFURL, CGRect};
     use objc2_core_graphics::CGRectMakeWithDictionaryRepresentation;
 
-    use crate::Bounds;
+    use crate::{AppIdentity, Bounds, ProcessInfo, WindowKind};
 
     use super::{MacOSError, WindowInfo};
 
@@ -124,6 +192,176 @@ pub mod window {
         pub fn owner_name(&self) -> Option<String> {
             self.0.owner_name().map(|name| name.to_string())
         }
+
+        /// Returns the path of the executable file of the process that owns the window
+        /// by `proc_pidpath`.
+        pub fn owner_executable(&self) -> Option<PathBuf> {
+            process_executable(self.owner_pid())
+        }
+
+        /// Returns the information of the process that owns the window.
+        ///
+        /// The parent process ID, the user ID and the start time are read by `proc_pidinfo`
+        /// with `PROC_PIDTBSDINFO`. The command line is not provided.
+        pub fn owner_process(&self) -> ProcessInfo {
+            let pid = self.owner_pid();
+
+            let mut info = ProcessInfo::new(pid);
+            info.name = self.owner_name();
+            info.executable = self.owner_executable();
+
+            if let Some(bsd_info) = process_bsd_info(pid) {
+                info.parent_pid = Some(bsd_info.pbi_ppid as _);
+                info.user_id = Some(bsd_info.pbi_ruid);
+                info.start_time = Some(
+                    SystemTime::UNIX_EPOCH
+                        + Duration::from_secs(bsd_info.pbi_start_tvsec)
+                        + Duration::from_micros(bsd_info.pbi_start_tvusec),
+                );
+            }
+
+            info
+        }
+
+        /// Returns the identity of the application.
+        ///
+        /// The identifier is the bundle identifier of the innermost `.app` bundle
+        /// which contains the [executable](Self::owner_executable).
+        pub fn app_id(&self) -> AppIdentity {
+            let executable = self.owner_executable();
+
+            AppIdentity {
+                id: executable.as_deref().and_then(bundle_identifier),
+                instance: None,
+                executable,
+            }
+        }
+
+        /// Returns the layer of the window. The normal windows are in the layer `0`.
+        pub fn layer(&self) -> i32 {
+            self.0.layer().as_i32().expect("invalid window layer value")
+        }
+
+        /// Guesses the type of the window from its [layer](Self::layer).
+        ///
+        /// The layers are the window levels of `CGWindowLevel.h`, e.g. `kCGDockWindowLevel`.
+        /// The panels of the applications are usually in the normal layer,
+        /// so they are [`Normal`](WindowKind::Normal).
+        pub fn kind(&self) -> WindowKind {
+            /// `kCGDesktopIconWindowLevel`. The desktop is in the layers below it.
+            const DESKTOP_ICON_LEVEL: i32 = i32::MIN + 40;
+
+            match self.layer() {
+                ..=DESKTOP_ICON_LEVEL => WindowKind::Desktop,
+                // `kCGNormalWindowLevel`
+                0 => WindowKind::Normal,
+                // `kCGFloatingWindowLevel` and `kCGUtilityWindowLevel`
+                3 | 19 => WindowKind::Utility,
+                // `kCGModalPanelWindowLevel`
+                8 => WindowKind::Dialog,
+                // `kCGDockWindowLevel`, `kCGMainMenuWindowLevel` and `kCGStatusWindowLevel`
+                20 | 24 | 25 => WindowKind::Dock,
+                // `kCGPopUpMenuWindowLevel`
+                101 => WindowKind::Menu,
+                // `kCGHelpWindowLevel`
+                200 => WindowKind::Tooltip,
+                _ => WindowKind::Other,
+            }
+        }
+
+        /// Checks if the window is on the screen by `kCGWindowIsOnscreen`.
+        pub fn is_on_screen(&self) -> bool {
+            self.0
+                .is_on_screen()
+                .is_some_and(|is_on_screen| is_on_screen.as_bool())
+        }
+    }
+
+    /// `PROC_PIDPATHINFO_MAXSIZE` of `libproc.h`.
+    const PROC_PIDPATHINFO_MAXSIZE: usize = 4 * 1024;
+
+    /// `PROC_PIDTBSDINFO` of `sys/proc_info.h`.
+    const PROC_PIDTBSDINFO: i32 = 3;
+
+    /// `struct proc_bsdinfo` of `sys/proc_info.h`. Only some of the fields are read.
+    #[repr(C)]
+    #[derive(Default)]
+    #[allow(dead_code)]
+    struct ProcBsdInfo {
+        pbi_flags: u32,
+        pbi_status: u32,
+        pbi_xstatus: u32,
+        pbi_pid: u32,
+        pbi_ppid: u32,
+        pbi_uid: u32,
+        pbi_gid: u32,
+        pbi_ruid: u32,
+        pbi_rgid: u32,
+        pbi_svuid: u32,
+        pbi_svgid: u32,
+        rfu_1: u32,
+        pbi_comm: [u8; 16],
+        pbi_name: [u8; 32],
+        pbi_nfiles: u32,
+        pbi_pgid: u32,
+        pbi_pjobc: u32,
+        e_tdev: u32,
+        e_tpgid: u32,
+        pbi_nice: i32,
+        pbi_start_tvsec: u64,
+        pbi_start_tvusec: u64,
+    }
+
+    unsafe extern "C" {
+        /// Declared in `libproc.h`, which is a part of `libSystem`.
+        fn proc_pidpath(pid: i32, buffer: *mut c_void, buffersize: u32) -> i32;
+        /// Declared in `libproc.h`, which is a part of `libSystem`.
+        fn proc_pidinfo(
+            pid: i32,
+            flavor: i32,
+            arg: u64,
+            buffer: *mut c_void,
+            buffersize: i32,
+        ) -> i32;
+    }
+
+    fn process_bsd_info(pid: i32) -> Option<ProcBsdInfo> {
+        let mut info = ProcBsdInfo::default();
+        let size = size_of::<ProcBsdInfo>() as i32;
+        let length = unsafe {
+            proc_pidinfo(
+                pid,
+                PROC_PIDTBSDINFO,
+                0,
+                (&mut info as *mut ProcBsdInfo).cast(),
+                size,
+            )
+        };
+
+        (length == size).then_some(info)
+    }
+
+    fn process_executable(pid: i32) -> Option<PathBuf> {
+        let mut buffer = vec![0u8; PROC_PIDPATHINFO_MAXSIZE];
+        let length = unsafe { proc_pidpath(pid, buffer.as_mut_ptr().cast(), buffer.len() as u32) };
+
+        if length <= 0 {
+            return None;
+        }
+
+        buffer.truncate(length as usize);
+        Some(OsString::from_vec(buffer).into())
+    }
+
+    /// Reads the bundle identifier of the innermost `.app` bundle which contains the file.
+    fn bundle_identifier(path: &Path) -> Option<String> {
+        let bundle_path = path
+            .ancestors()
+            .find(|path| path.extension().is_some_and(|extension| extension == "app"))?;
+        let url = CFURL::from_directory_path(bundle_path)?;
+        let bundle = CFBundle::new(None, Some(&url))?;
+
+        Some(bundle.identifier()?.to_string())
     }
 }
 
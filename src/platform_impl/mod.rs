@@ -1,17 +1,31 @@
 //! Platform-specific implementations for window.
 
+#[cfg(target_os = "linux")]
+pub mod linux;
 #[cfg(target_os = "macos")]
 pub mod macos;
+#[cfg(target_os = "linux")]
+pub mod wayland;
 #[cfg(target_os = "windows")]
 pub mod windows;
+#[cfg(target_os = "linux")]
+pub mod x11;
 
+#[cfg(target_os = "linux")]
+pub use linux::{
+    LinuxBounds as PlatformBounds, LinuxError as PlatformError, LinuxWindow as PlatformWindow,
+    LinuxWindowId as PlatformWindowId, get_foreground_window, get_monitors, get_window,
+    get_windows,
+};
 #[cfg(target_os = "macos")]
 pub use macos::{
     MacOSBounds as PlatformBounds, MacOSError as PlatformError, MacOSWindow as PlatformWindow,
-    MacOSWindowId as PlatformWindowId, get_window, get_windows,
+    MacOSWindowId as PlatformWindowId, get_foreground_window, get_monitors, get_window,
+    get_windows,
 };
 #[cfg(target_os = "windows")]
 pub use windows::{
     WindowsBounds as PlatformBounds, WindowsError as PlatformError,
-    WindowsWindow as PlatformWindow, WindowsWindowId as PlatformWindowId, get_window, get_windows,
+    WindowsWindow as PlatformWindow, WindowsWindowId as PlatformWindowId, get_foreground_window,
+    get_monitors, get_window, get_windows,
 };
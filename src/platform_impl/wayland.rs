@@ -0,0 +1,848 @@
+use crate::{Error, Monitor, Window};
+
+use super::linux::LinuxWindow;
+
+pub use error::WaylandError;
+pub use window::{WaylandWindow, WaylandWindowState};
+
+pub(crate) use notifier::WaylandNotifier;
+
+pub type WaylandWindowId = u32;
+
+/// The protocols which can be used to retrieve the windows from the compositor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WaylandProtocol {
+    /// [`ext-foreign-toplevel-list-v1`](https://wayland.app/protocols/ext-foreign-toplevel-list-v1).
+    /// It provides the title, the application ID and a stable identifier.
+    ExtForeignToplevelList,
+    /// [`wlr-foreign-toplevel-management-unstable-v1`](https://wayland.app/protocols/wlr-foreign-toplevel-management-unstable-v1).
+    /// It provides the title, the application ID, the state and the parent of the windows.
+    WlrForeignToplevelManagement,
+}
+
+impl WaylandProtocol {
+    /// Returns the name of the global interface of the protocol.
+    pub fn interface(&self) -> &'static str {
+        match self {
+            Self::ExtForeignToplevelList => "ext_foreign_toplevel_list_v1",
+            Self::WlrForeignToplevelManagement => "zwlr_foreign_toplevel_manager_v1",
+        }
+    }
+}
+
+/// Retrieves a window by its unique identifier.
+pub fn get_window(id: WaylandWindowId) -> Result<Option<Window>, Error> {
+    get_window_with(id, None)
+}
+
+/// Retrieves a window by its unique identifier through the given protocol.
+/// If `protocol` is `None`, the protocol is chosen as same as [`get_windows`].
+pub fn get_window_with(
+    id: WaylandWindowId,
+    protocol: Option<WaylandProtocol>,
+) -> Result<Option<Window>, Error> {
+    let window = session::with_session(|session| session.windows(protocol))?
+        .into_iter()
+        .find(|window| window.id() == id);
+
+    Ok(window.map(|window| Window::new(LinuxWindow::Wayland(window))))
+}
+
+/// Retrieves a list of all toplevel windows announced by the compositor.
+///
+/// `zwlr_foreign_toplevel_manager_v1` is used if the compositor supports it,
+/// otherwise `ext_foreign_toplevel_list_v1` is used.
+/// The windows are in the order the compositor announced them,
+/// because neither protocol exposes the stacking order.
+pub fn get_windows() -> Result<Vec<Window>, Error> {
+    get_windows_with(None)
+}
+
+/// Retrieves a list of all toplevel windows announced by the compositor through
+/// the given protocol. If `protocol` is `None`, the protocol is chosen as same as [`get_windows`].
+pub fn get_windows_with(protocol: Option<WaylandProtocol>) -> Result<Vec<Window>, Error> {
+    let windows = session::with_session(|session| session.windows(protocol))?
+        .into_iter()
+        .map(|window| Window::new(LinuxWindow::Wayland(window)))
+        .collect();
+
+    Ok(windows)
+}
+
+/// Retrieves the activated window through the given protocol.
+/// If `protocol` is `None`, the protocol is chosen as same as [`get_windows`].
+///
+/// It returns [`Error::Unsupported`] on `ext_foreign_toplevel_list_v1`,
+/// which does not provide the state of the windows.
+pub fn get_foreground_window_with(
+    protocol: Option<WaylandProtocol>,
+) -> Result<Option<Window>, Error> {
+    let (protocol, windows) = session::with_session(|session| {
+        let protocol = session.resolve_protocol(protocol)?;
+        Ok((protocol, session.windows(Some(protocol))?))
+    })?;
+
+    if protocol == WaylandProtocol::ExtForeignToplevelList {
+        return Err(Error::Unsupported("get_foreground_window"));
+    }
+
+    let window = windows
+        .into_iter()
+        .find(|window| window.is_activated() == Some(true));
+
+    Ok(window.map(|window| Window::new(LinuxWindow::Wayland(window))))
+}
+
+/// Retrieves the monitors, which are the `wl_output`s announced by the compositor.
+///
+/// The bounds are the logical position and size sent by `zxdg_output_manager_v1`
+/// if the compositor supports it. Otherwise, they are computed from the position and
+/// the current mode of the output.
+pub fn get_monitors() -> Result<Vec<Monitor>, Error> {
+    Ok(session::with_session(|session| Ok(session.monitors()))?)
+}
+
+/// Returns the protocols supported by the compositor.
+///
+/// It returns [`WaylandError::UnsupportedProtocol`] when the compositor supports none of them.
+pub fn supported_protocols() -> Result<Vec<WaylandProtocol>, Error> {
+    Ok(session::with_session(|session| {
+        Ok(session.protocols().collect())
+    })?)
+}
+
+/// The connection to the compositor kept for the whole process.
+///
+/// Toplevel handles are only announced once per binding, so the connection is kept
+/// and refreshed by a roundtrip instead of being created for each call.
+/// It also keeps the handles of `zwlr_foreign_toplevel_manager_v1` alive, whose object IDs
+/// are used as the window IDs.
+mod session {
+    use std::sync::{Mutex, PoisonError};
+
+    use wayland_client::{
+        Connection, Dispatch, EventQueue, Proxy, QueueHandle, WEnum, event_created_child,
+        globals::{GlobalListContents, registry_queue_init},
+        protocol::{
+            wl_output::{self, WlOutput},
+            wl_registry::{self, WlRegistry},
+        },
+    };
+    use wayland_protocols::{
+        ext::foreign_toplevel_list::v1::client::{
+            ext_foreign_toplevel_handle_v1::{self, ExtForeignToplevelHandleV1},
+            ext_foreign_toplevel_list_v1::{self, ExtForeignToplevelListV1},
+        },
+        xdg::xdg_output::zv1::client::{
+            zxdg_output_manager_v1::ZxdgOutputManagerV1,
+            zxdg_output_v1::{self, ZxdgOutputV1},
+        },
+    };
+    use wayland_protocols_wlr::foreign_toplevel::v1::client::{
+        zwlr_foreign_toplevel_handle_v1::{self, ZwlrForeignToplevelHandleV1},
+        zwlr_foreign_toplevel_manager_v1::{self, ZwlrForeignToplevelManagerV1},
+    };
+
+    use crate::{Bounds, CoordinateSpace, Monitor};
+
+    use super::{WaylandError, WaylandProtocol, WaylandWindow, WaylandWindowId};
+
+    static SESSION: Mutex<Option<Session>> = Mutex::new(None);
+
+    /// Runs `f` with the up-to-date session, connecting to the compositor if needed.
+    /// The session is dropped when an error occurs so the next call reconnects.
+    pub fn with_session<R>(
+        f: impl FnOnce(&mut Session) -> Result<R, WaylandError>,
+    ) -> Result<R, WaylandError> {
+        let mut guard = SESSION.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let session = match guard.as_mut() {
+            Some(session) => session,
+            None => guard.insert(Session::connect()?),
+        };
+
+        let result = session.refresh().and_then(|()| f(session));
+        if matches!(result, Err(WaylandError::Dispatch(_))) {
+            *guard = None;
+        }
+
+        result
+    }
+
+    pub struct Session {
+        connection: Connection,
+        queue: EventQueue<State>,
+        state: State,
+        ext_list: Option<ExtForeignToplevelListV1>,
+        wlr_manager: Option<ZwlrForeignToplevelManagerV1>,
+    }
+
+    impl Session {
+        fn connect() -> Result<Self, WaylandError> {
+            let connection = Connection::connect_to_env()?;
+            let (globals, mut queue) = registry_queue_init::<State>(&connection)?;
+            let handle = queue.handle();
+
+            let ext_list = globals.bind(&handle, 1..=1, ()).ok();
+            let wlr_manager = globals.bind(&handle, 1..=3, ()).ok();
+
+            if ext_list.is_none() && wlr_manager.is_none() {
+                return Err(WaylandError::UnsupportedProtocol(
+                    WaylandProtocol::ExtForeignToplevelList.interface(),
+                ));
+            }
+
+            let mut state = State {
+                xdg_output_manager: globals.bind(&handle, 1..=3, ()).ok(),
+                ..Default::default()
+            };
+            for global in globals.contents().clone_list() {
+                if global.interface == WlOutput::interface().name {
+                    state.add_output(globals.registry(), &handle, global.name, global.version);
+                }
+            }
+            queue.roundtrip(&mut state)?;
+
+            Ok(Self {
+                connection,
+                queue,
+                state,
+                ext_list,
+                wlr_manager,
+            })
+        }
+
+        /// Returns the connection to the compositor.
+        pub fn connection(&self) -> &Connection {
+            &self.connection
+        }
+
+        /// Processes the events sent by the compositor since the last call.
+        fn refresh(&mut self) -> Result<(), WaylandError> {
+            self.queue.roundtrip(&mut self.state)?;
+            Ok(())
+        }
+
+        /// Returns the protocols supported by the compositor, in order of preference.
+        pub fn protocols(&self) -> impl Iterator<Item = WaylandProtocol> {
+            [
+                self.wlr_manager
+                    .as_ref()
+                    .map(|_| WaylandProtocol::WlrForeignToplevelManagement),
+                self.ext_list
+                    .as_ref()
+                    .map(|_| WaylandProtocol::ExtForeignToplevelList),
+            ]
+            .into_iter()
+            .flatten()
+        }
+
+        /// Returns the protocol to use for `protocol`.
+        /// If `protocol` is `None`, the most preferred protocol is returned.
+        pub fn resolve_protocol(
+            &self,
+            protocol: Option<WaylandProtocol>,
+        ) -> Result<WaylandProtocol, WaylandError> {
+            match protocol {
+                Some(protocol) if self.protocols().any(|supported| supported == protocol) => {
+                    Ok(protocol)
+                }
+                Some(protocol) => Err(WaylandError::UnsupportedProtocol(protocol.interface())),
+                None => Ok(self
+                    .protocols()
+                    .next()
+                    .expect("at least one protocol is bound on connect")),
+            }
+        }
+
+        /// Returns the outputs whose initial properties have been sent as monitors.
+        pub fn monitors(&self) -> Vec<Monitor> {
+            self.state
+                .outputs
+                .iter()
+                .filter(|output| output.done)
+                .map(Output::monitor)
+                .collect()
+        }
+
+        /// Returns the toplevels whose initial properties have been sent through the protocol.
+        /// If `protocol` is `None`, the most preferred protocol is used.
+        pub fn windows(
+            &self,
+            protocol: Option<WaylandProtocol>,
+        ) -> Result<Vec<WaylandWindow>, WaylandError> {
+            let protocol = self.resolve_protocol(protocol)?;
+
+            let windows = match protocol {
+                WaylandProtocol::ExtForeignToplevelList => self
+                    .state
+                    .ext_toplevels
+                    .iter()
+                    .filter_map(|toplevel| toplevel.current.clone())
+                    .collect(),
+                WaylandProtocol::WlrForeignToplevelManagement => self
+                    .state
+                    .wlr_toplevels
+                    .iter()
+                    .filter_map(|toplevel| toplevel.current.clone())
+                    .collect(),
+            };
+
+            Ok(windows)
+        }
+    }
+
+    #[derive(Default)]
+    pub struct State {
+        ext_toplevels: Vec<Toplevel<ExtForeignToplevelHandleV1>>,
+        wlr_toplevels: Vec<Toplevel<ZwlrForeignToplevelHandleV1>>,
+        outputs: Vec<Output>,
+        xdg_output_manager: Option<ZxdgOutputManagerV1>,
+    }
+
+    impl State {
+        /// Binds the `wl_output` global. Its name is set as the user data of the objects.
+        fn add_output(
+            &mut self,
+            registry: &WlRegistry,
+            handle: &QueueHandle<Self>,
+            global: u32,
+            version: u32,
+        ) {
+            let output: WlOutput = registry.bind(global, version.min(4), handle, global);
+            let xdg_output = self
+                .xdg_output_manager
+                .as_ref()
+                .map(|manager| manager.get_xdg_output(&output, handle, global));
+
+            self.outputs.push(Output {
+                global,
+                output,
+                xdg_output,
+                name: None,
+                position: (0, 0),
+                mode_size: (0, 0),
+                scale: 1,
+                logical_position: None,
+                logical_size: None,
+                done: false,
+            });
+        }
+
+        fn remove_output(&mut self, global: u32) {
+            let Some(index) = self
+                .outputs
+                .iter()
+                .position(|output| output.global == global)
+            else {
+                return;
+            };
+            let output = self.outputs.remove(index);
+
+            if let Some(xdg_output) = output.xdg_output {
+                xdg_output.destroy();
+            }
+            if output.output.version() >= 3 {
+                output.output.release();
+            }
+        }
+
+        fn output_mut(&mut self, global: u32) -> Option<&mut Output> {
+            self.outputs
+                .iter_mut()
+                .find(|output| output.global == global)
+        }
+
+        /// Returns the window ID for the identifier of an `ext_foreign_toplevel_handle_v1`.
+        ///
+        /// It is the hash of the identifier, or the next free value when another toplevel
+        /// already has the hash, so the IDs of the open windows never collide.
+        fn ext_window_id(&self, identifier: &str) -> WaylandWindowId {
+            let mut id = hash_identifier(identifier);
+            while self.ext_toplevels.iter().any(|toplevel| {
+                toplevel.pending.id == id
+                    && toplevel
+                        .pending
+                        .identifier
+                        .as_deref()
+                        .is_some_and(|other| other != identifier)
+            }) {
+                id = id.wrapping_add(1);
+            }
+
+            id
+        }
+    }
+
+    /// Hashes the identifier with 32-bit FNV-1a to make it fit in a [`WindowId`](crate::WindowId).
+    /// The hash is stable so the IDs can be shared between processes.
+    fn hash_identifier(identifier: &str) -> WaylandWindowId {
+        identifier.bytes().fold(0x811c9dc5, |hash, byte| {
+            (hash ^ byte as u32).wrapping_mul(0x01000193)
+        })
+    }
+
+    /// A `wl_output` and the properties sent for it.
+    struct Output {
+        global: u32,
+        output: WlOutput,
+        xdg_output: Option<ZxdgOutputV1>,
+        name: Option<String>,
+        position: (i32, i32),
+        mode_size: (i32, i32),
+        scale: i32,
+        logical_position: Option<(i32, i32)>,
+        logical_size: Option<(i32, i32)>,
+        /// Whether the initial properties have been sent.
+        done: bool,
+    }
+
+    impl Output {
+        fn monitor(&self) -> Monitor {
+            let scale = self.scale.max(1);
+            let (x, y) = self.logical_position.unwrap_or(self.position);
+            let (width, height) = self
+                .logical_size
+                .unwrap_or((self.mode_size.0 / scale, self.mode_size.1 / scale));
+            let bounds = Bounds::new(x.into(), y.into(), width.into(), height.into());
+
+            Monitor {
+                id: self.global.into(),
+                name: self.name.clone(),
+                work_area: bounds.clone(),
+                bounds,
+                scale_factor: scale.into(),
+                is_primary: false,
+                coordinate_space: CoordinateSpace::Logical,
+            }
+        }
+    }
+
+    struct Toplevel<H> {
+        handle: H,
+        pending: WaylandWindow,
+        current: Option<WaylandWindow>,
+    }
+
+    impl<H: Proxy> Toplevel<H> {
+        fn new(handle: H, pending: WaylandWindow) -> Self {
+            Self {
+                handle,
+                pending,
+                current: None,
+            }
+        }
+
+        fn position(toplevels: &[Self], handle: &H) -> Option<usize> {
+            toplevels
+                .iter()
+                .position(|toplevel| toplevel.handle.id() == handle.id())
+        }
+    }
+
+    impl Dispatch<WlRegistry, GlobalListContents> for State {
+        fn event(
+            state: &mut Self,
+            registry: &WlRegistry,
+            event: wl_registry::Event,
+            _: &GlobalListContents,
+            _: &Connection,
+            handle: &QueueHandle<Self>,
+        ) {
+            match event {
+                wl_registry::Event::Global {
+                    name,
+                    interface,
+                    version,
+                } if interface == WlOutput::interface().name => {
+                    state.add_output(registry, handle, name, version);
+                }
+                wl_registry::Event::GlobalRemove { name } => state.remove_output(name),
+                _ => {}
+            }
+        }
+    }
+
+    impl Dispatch<WlOutput, u32> for State {
+        fn event(
+            state: &mut Self,
+            _: &WlOutput,
+            event: wl_output::Event,
+            global: &u32,
+            _: &Connection,
+            _: &QueueHandle<Self>,
+        ) {
+            let Some(output) = state.output_mut(*global) else {
+                return;
+            };
+
+            match event {
+                wl_output::Event::Geometry { x, y, .. } => output.position = (x, y),
+                wl_output::Event::Mode {
+                    flags: WEnum::Value(flags),
+                    width,
+                    height,
+                    ..
+                } if flags.contains(wl_output::Mode::Current) => {
+                    output.mode_size = (width, height);
+                }
+                wl_output::Event::Scale { factor } => output.scale = factor,
+                wl_output::Event::Name { name } => output.name = Some(name),
+                wl_output::Event::Done => output.done = true,
+                _ => {}
+            }
+        }
+    }
+
+    impl Dispatch<ZxdgOutputManagerV1, ()> for State {
+        fn event(
+            _: &mut Self,
+            _: &ZxdgOutputManagerV1,
+            _: <ZxdgOutputManagerV1 as Proxy>::Event,
+            _: &(),
+            _: &Connection,
+            _: &QueueHandle<Self>,
+        ) {
+        }
+    }
+
+    impl Dispatch<ZxdgOutputV1, u32> for State {
+        fn event(
+            state: &mut Self,
+            _: &ZxdgOutputV1,
+            event: zxdg_output_v1::Event,
+            global: &u32,
+            _: &Connection,
+            _: &QueueHandle<Self>,
+        ) {
+            let Some(output) = state.output_mut(*global) else {
+                return;
+            };
+
+            match event {
+                zxdg_output_v1::Event::LogicalPosition { x, y } => {
+                    output.logical_position = Some((x, y));
+                }
+                zxdg_output_v1::Event::LogicalSize { width, height } => {
+                    output.logical_size = Some((width, height));
+                }
+                zxdg_output_v1::Event::Name { name } => {
+                    output.name.get_or_insert(name);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    impl Dispatch<ExtForeignToplevelListV1, ()> for State {
+        fn event(
+            state: &mut Self,
+            _: &ExtForeignToplevelListV1,
+            event: ext_foreign_toplevel_list_v1::Event,
+            _: &(),
+            _: &Connection,
+            _: &QueueHandle<Self>,
+        ) {
+            if let ext_foreign_toplevel_list_v1::Event::Toplevel { toplevel } = event {
+                state
+                    .ext_toplevels
+                    .push(Toplevel::new(toplevel, WaylandWindow::default()));
+            }
+        }
+
+        event_created_child!(State, ExtForeignToplevelListV1, [
+            ext_foreign_toplevel_list_v1::EVT_TOPLEVEL_OPCODE => (ExtForeignToplevelHandleV1, ())
+        ]);
+    }
+
+    impl Dispatch<ExtForeignToplevelHandleV1, ()> for State {
+        fn event(
+            state: &mut Self,
+            handle: &ExtForeignToplevelHandleV1,
+            event: ext_foreign_toplevel_handle_v1::Event,
+            _: &(),
+            _: &Connection,
+            _: &QueueHandle<Self>,
+        ) {
+            use ext_foreign_toplevel_handle_v1::Event;
+
+            let Some(index) = Toplevel::position(&state.ext_toplevels, handle) else {
+                return;
+            };
+            let toplevel = &mut state.ext_toplevels[index];
+
+            match event {
+                Event::Identifier { identifier } => {
+                    let id = state.ext_window_id(&identifier);
+                    state.ext_toplevels[index]
+                        .pending
+                        .set_identifier(id, identifier);
+                }
+                Event::Title { title } => toplevel.pending.title = Some(title),
+                Event::AppId { app_id } => toplevel.pending.app_id = Some(app_id),
+                Event::Done => toplevel.current = Some(toplevel.pending.clone()),
+                Event::Closed => {
+                    state.ext_toplevels.remove(index).handle.destroy();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    impl Dispatch<ZwlrForeignToplevelManagerV1, ()> for State {
+        fn event(
+            state: &mut Self,
+            _: &ZwlrForeignToplevelManagerV1,
+            event: zwlr_foreign_toplevel_manager_v1::Event,
+            _: &(),
+            _: &Connection,
+            _: &QueueHandle<Self>,
+        ) {
+            if let zwlr_foreign_toplevel_manager_v1::Event::Toplevel { toplevel } = event {
+                let window = WaylandWindow {
+                    id: toplevel.id().protocol_id(),
+                    state: Some(Default::default()),
+                    ..Default::default()
+                };
+
+                state.wlr_toplevels.push(Toplevel::new(toplevel, window));
+            }
+        }
+
+        event_created_child!(State, ZwlrForeignToplevelManagerV1, [
+            zwlr_foreign_toplevel_manager_v1::EVT_TOPLEVEL_OPCODE => (ZwlrForeignToplevelHandleV1, ())
+        ]);
+    }
+
+    impl Dispatch<ZwlrForeignToplevelHandleV1, ()> for State {
+        fn event(
+            state: &mut Self,
+            handle: &ZwlrForeignToplevelHandleV1,
+            event: zwlr_foreign_toplevel_handle_v1::Event,
+            _: &(),
+            _: &Connection,
+            _: &QueueHandle<Self>,
+        ) {
+            use zwlr_foreign_toplevel_handle_v1::Event;
+
+            let Some(index) = Toplevel::position(&state.wlr_toplevels, handle) else {
+                return;
+            };
+            let toplevel = &mut state.wlr_toplevels[index];
+
+            match event {
+                Event::Title { title } => toplevel.pending.title = Some(title),
+                Event::AppId { app_id } => toplevel.pending.app_id = Some(app_id),
+                Event::State { state } => toplevel.pending.set_wlr_state(&state),
+                Event::Parent { parent } => {
+                    toplevel.pending.parent = parent.map(|parent| parent.id().protocol_id());
+                }
+                Event::OutputEnter { output } => {
+                    if let Some(&global) = output.data::<u32>()
+                        && !toplevel.pending.outputs.contains(&global)
+                    {
+                        toplevel.pending.outputs.push(global);
+                    }
+                }
+                Event::OutputLeave { output } => {
+                    if let Some(global) = output.data::<u32>() {
+                        toplevel.pending.outputs.retain(|output| output != global);
+                    }
+                }
+                Event::Done => toplevel.current = Some(toplevel.pending.clone()),
+                Event::Closed => {
+                    state.wlr_toplevels.remove(index).handle.destroy();
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+mod notifier {
+    use std::{
+        os::fd::{AsFd, BorrowedFd},
+        time::Duration,
+    };
+
+    use wayland_client::backend::Backend;
+
+    use super::{WaylandError, session};
+
+    /// Waits for the events from the compositor on the connection shared by
+    /// [`get_windows`](super::get_windows).
+    ///
+    /// The events are not read here but by the next roundtrip of the session,
+    /// so the window IDs stay the same as the ones of [`get_windows`](super::get_windows).
+    pub(crate) struct WaylandNotifier {
+        backend: Backend,
+    }
+
+    impl WaylandNotifier {
+        pub fn new() -> Result<Self, WaylandError> {
+            let backend = session::with_session(|session| Ok(session.connection().backend()))?;
+
+            Ok(Self { backend })
+        }
+
+        /// Waits until the compositor sends events or the timeout elapses.
+        pub fn wait(&mut self, timeout: Duration) {
+            super::super::linux::wait_readable(self.as_fd(), timeout);
+        }
+    }
+
+    impl AsFd for WaylandNotifier {
+        fn as_fd(&self) -> BorrowedFd<'_> {
+            self.backend.poll_fd()
+        }
+    }
+}
+
+pub mod window {
+    use wayland_protocols_wlr::foreign_toplevel::v1::client::zwlr_foreign_toplevel_handle_v1;
+
+    use super::WaylandWindowId;
+
+    /// The state of a toplevel window sent by `zwlr_foreign_toplevel_handle_v1`.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    pub struct WaylandWindowState {
+        pub maximized: bool,
+        pub minimized: bool,
+        pub activated: bool,
+        pub fullscreen: bool,
+    }
+
+    /// Represents a toplevel window announced by the Wayland compositor.
+    ///
+    /// Unlike the other platforms, it is a snapshot of the properties at the time
+    /// when the window was retrieved.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub struct WaylandWindow {
+        pub(crate) id: WaylandWindowId,
+        pub(crate) identifier: Option<String>,
+        pub(crate) title: Option<String>,
+        pub(crate) app_id: Option<String>,
+        pub(crate) state: Option<WaylandWindowState>,
+        pub(crate) parent: Option<WaylandWindowId>,
+        pub(crate) outputs: Vec<u32>,
+    }
+
+    impl WaylandWindow {
+        /// Returns the window unique identifier.
+        ///
+        /// On `ext_foreign_toplevel_list_v1`, it is a hash of [`identifier`](Self::identifier).
+        /// If another open window already has the hash, the next free value is used instead,
+        /// so the ID of such a window may differ between processes.
+        /// On `zwlr_foreign_toplevel_manager_v1`, it is the object ID of the toplevel handle,
+        /// which is valid while the window is open and the process is running.
+        pub fn id(&self) -> WaylandWindowId {
+            self.id
+        }
+
+        /// Returns the identifier sent by the compositor.
+        /// It is unique and never reused for the lifetime of the compositor.
+        ///
+        /// It is only available on `ext_foreign_toplevel_list_v1`.
+        pub fn identifier(&self) -> Option<&str> {
+            self.identifier.as_deref()
+        }
+
+        /// Returns the title of the window.
+        pub fn title(&self) -> Option<&str> {
+            self.title.as_deref()
+        }
+
+        /// Returns the application ID of the window.
+        /// It usually matches the name of the `.desktop` file.
+        pub fn app_id(&self) -> Option<&str> {
+            self.app_id.as_deref()
+        }
+
+        /// Returns the state of the window.
+        ///
+        /// It is only available on `zwlr_foreign_toplevel_manager_v1`.
+        pub fn state(&self) -> Option<WaylandWindowState> {
+            self.state
+        }
+
+        /// Checks if the window is activated, i.e. it has the keyboard focus.
+        ///
+        /// It is only available on `zwlr_foreign_toplevel_manager_v1`.
+        pub fn is_activated(&self) -> Option<bool> {
+            self.state.map(|state| state.activated)
+        }
+
+        /// Returns the ID of the parent window, e.g. the main window of a dialog.
+        ///
+        /// It is only available on `zwlr_foreign_toplevel_manager_v1` version 3 or later.
+        pub fn parent(&self) -> Option<WaylandWindowId> {
+            self.parent
+        }
+
+        /// Returns the names of the `wl_output` globals the window is on,
+        /// in the order the window entered them.
+        ///
+        /// It is only available on `zwlr_foreign_toplevel_manager_v1`.
+        pub fn outputs(&self) -> &[u32] {
+            &self.outputs
+        }
+
+        pub(crate) fn set_identifier(&mut self, id: WaylandWindowId, identifier: String) {
+            self.id = id;
+            self.identifier = Some(identifier);
+        }
+
+        /// Sets the state from the array of `zwlr_foreign_toplevel_handle_v1::state` values.
+        pub(crate) fn set_wlr_state(&mut self, raw: &[u8]) {
+            use zwlr_foreign_toplevel_handle_v1::State;
+
+            let mut state = WaylandWindowState::default();
+            for value in raw.chunks_exact(4) {
+                let value = u32::from_ne_bytes([value[0], value[1], value[2], value[3]]);
+
+                match State::try_from(value) {
+                    Ok(State::Maximized) => state.maximized = true,
+                    Ok(State::Minimized) => state.minimized = true,
+                    Ok(State::Activated) => state.activated = true,
+                    Ok(State::Fullscreen) => state.fullscreen = true,
+                    _ => {}
+                }
+            }
+
+            self.state = Some(state);
+        }
+    }
+}
+
+pub mod error {
+    /// Low-level errors that can occur when interacting with the Wayland compositor.
+    #[derive(Debug, thiserror::Error)]
+    pub enum WaylandError {
+        /// Represents a failure to connect to the compositor.
+        #[error("Failed to connect to the Wayland compositor: {0}")]
+        Connect(#[from] wayland_client::ConnectError),
+        /// Represents a failure to retrieve the globals of the compositor.
+        #[error("Failed to retrieve the Wayland globals: {0}")]
+        Global(#[from] wayland_client::globals::GlobalError),
+        /// Represents a failure while processing the events of the compositor.
+        #[error("Failed to dispatch the Wayland events: {0}")]
+        Dispatch(#[from] wayland_client::DispatchError),
+        /// Represents a situation when the compositor does not support the protocol.
+        #[error("The compositor does not support `{0}`.")]
+        UnsupportedProtocol(&'static str),
+    }
+
+    impl WaylandError {
+        /// Returns `true` if the error means that the Wayland backend cannot be used,
+        /// i.e. there is no compositor or it lacks the protocol.
+        pub fn is_unavailable(&self) -> bool {
+            matches!(self, Self::Connect(_) | Self::UnsupportedProtocol(_))
+        }
+    }
+
+    impl From<WaylandError> for crate::Error {
+        fn from(error: WaylandError) -> Self {
+            Self::PlatformSpecificError(crate::platform_impl::linux::LinuxError::Wayland(error))
+        }
+    }
+}
@@ -1,13 +1,20 @@
 use windows::{
     Win32::{
         Foundation::LPARAM,
-        Foundation::{HWND, RECT},
-        UI::WindowsAndMessaging::IsWindow,
+        Foundation::{HWND, POINT, RECT},
+        Graphics::Gdi::{
+            EnumDisplayMonitors, GetMonitorInfoW, HDC, HMONITOR, MONITORINFO, MONITORINFOEXW,
+        },
+        UI::HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI},
+        UI::WindowsAndMessaging::{
+            GA_ROOT, GetAncestor, GetForegroundWindow, IsWindow, MONITORINFOF_PRIMARY,
+            WindowFromPoint,
+        },
     },
     core::BOOL,
 };
 
-use crate::{Error, Window};
+use crate::{CoordinateSpace, Error, Monitor, Window, WindowQuery};
 
 pub use error::WindowsError;
 pub use window::WindowsWindow;
@@ -22,13 +29,62 @@ pub fn get_window(id: WindowsWindowId) -> Option<Window> {
     if hwnd.is_invalid() || !unsafe { IsWindow(Some(hwnd)) }.as_bool() {
         None
     } else {
-        Some(Window(WindowsWindow::new(hwnd)))
+        Some(Window::new(WindowsWindow::new(hwnd)))
     }
 }
 
+/// Retrieves the foreground window by [`GetForegroundWindow`].
+///
+/// [`GetForegroundWindow`]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getforegroundwindow
+pub fn get_foreground_window() -> Option<Window> {
+    let hwnd = unsafe { GetForegroundWindow() };
+
+    (!hwnd.is_invalid()).then(|| Window::new(WindowsWindow::new(hwnd)))
+}
+
+/// Retrieves the frontmost window matching the query at the point.
+///
+/// The top-level window at the point is found by [`WindowFromPoint`].
+/// If it does not match the query, the windows below it are looked for by their bounds.
+/// It returns `None` if the point is NaN or out of the range of `i32`.
+///
+/// [`WindowFromPoint`]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-windowfrompoint
+pub fn find_window_at(query: &WindowQuery, x: f64, y: f64) -> Result<Option<Window>, Error> {
+    let (Some(pixel_x), Some(pixel_y)) = (pixel_coordinate(x), pixel_coordinate(y)) else {
+        return Ok(None);
+    };
+    let point = POINT {
+        x: pixel_x,
+        y: pixel_y,
+    };
+    let hwnd = unsafe { WindowFromPoint(point) };
+
+    if !hwnd.is_invalid() {
+        let root = unsafe { GetAncestor(hwnd, GA_ROOT) };
+        let window = Window::new(WindowsWindow::new(if root.is_invalid() {
+            hwnd
+        } else {
+            root
+        }));
+
+        if query.matches(&window) {
+            return Ok(Some(window));
+        }
+    }
+
+    Ok(query.take_match_at(get_windows()?, x, y))
+}
+
+/// Converts the coordinate to the pixel containing it.
+/// It is `None` if the value is NaN or out of the range of `i32`.
+fn pixel_coordinate(value: f64) -> Option<i32> {
+    let value = value.floor();
+    (value >= i32::MIN.into() && value <= i32::MAX.into()).then_some(value as i32)
+}
+
 unsafe extern "system" fn enum_windows_callback(hwnd: HWND, lparam: LPARAM) -> BOOL {
     let windows = unsafe { &mut *(lparam.0 as *mut Vec<Window>) };
-    windows.push(Window(WindowsWindow::new(hwnd)));
+    windows.push(Window::new(WindowsWindow::new(hwnd)));
 
     BOOL::from(true)
 }
@@ -48,15 +104,107 @@ pub fn get_windows() -> Result<Vec<Window>, Error> {
     Ok(windows)
 }
 
+unsafe extern "system" fn enum_handles_callback(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    let handles = unsafe { &mut *(lparam.0 as *mut Vec<HWND>) };
+    handles.push(hwnd);
+
+    BOOL::from(true)
+}
+
+unsafe extern "system" fn enum_monitors_callback(
+    hmonitor: HMONITOR,
+    _: HDC,
+    _: *mut RECT,
+    lparam: LPARAM,
+) -> BOOL {
+    let monitors = unsafe { &mut *(lparam.0 as *mut Vec<HMONITOR>) };
+    monitors.push(hmonitor);
+
+    BOOL::from(true)
+}
+
+/// Retrieves the monitors by [`EnumDisplayMonitors`].
+///
+/// [`EnumDisplayMonitors`]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enumdisplaymonitors
+pub fn get_monitors() -> Result<Vec<Monitor>, Error> {
+    let mut handles = Vec::new();
+
+    // SAFETY: `Vec` should not be used during enumeration because it is used by mutable reference.
+    unsafe {
+        EnumDisplayMonitors(
+            None,
+            None,
+            Some(enum_monitors_callback),
+            LPARAM(&mut handles as *mut Vec<HMONITOR> as _),
+        )
+    }
+    .ok()?;
+
+    Ok(handles
+        .into_iter()
+        .map(monitor_info)
+        .collect::<Result<_, _>>()?)
+}
+
+/// Reads the information of the monitor by [`GetMonitorInfoW`] and [`GetDpiForMonitor`].
+///
+/// [`GetMonitorInfoW`]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getmonitorinfow
+/// [`GetDpiForMonitor`]: https://learn.microsoft.com/en-us/windows/win32/api/shellscalingapi/nf-shellscalingapi-getdpiformonitor
+fn monitor_info(hmonitor: HMONITOR) -> Result<Monitor, WindowsError> {
+    let mut info = MONITORINFOEXW::default();
+    info.monitorInfo.cbSize = std::mem::size_of::<MONITORINFOEXW>() as _;
+    unsafe {
+        GetMonitorInfoW(
+            hmonitor,
+            &mut info as *mut MONITORINFOEXW as *mut MONITORINFO,
+        )
+    }
+    .ok()?;
+
+    let (mut dpi_x, mut dpi_y) = (0, 0);
+    let scale_factor =
+        match unsafe { GetDpiForMonitor(hmonitor, MDT_EFFECTIVE_DPI, &mut dpi_x, &mut dpi_y) } {
+            Ok(()) => dpi_x as f64 / 96.0,
+            Err(_) => 1.0,
+        };
+
+    let device = &info.szDevice;
+    let length = device.iter().position(|&c| c == 0).unwrap_or(device.len());
+
+    Ok(Monitor {
+        id: hmonitor.0 as usize as u64,
+        name: Some(String::from_utf16_lossy(&device[..length])),
+        bounds: info.monitorInfo.rcMonitor.into(),
+        work_area: info.monitorInfo.rcWork.into(),
+        scale_factor,
+        is_primary: info.monitorInfo.dwFlags & MONITORINFOF_PRIMARY != 0,
+        coordinate_space: CoordinateSpace::Physical,
+    })
+}
+
 mod window {
+    use std::{
+        ffi::OsString,
+        os::windows::ffi::OsStringExt,
+        path::PathBuf,
+        time::{Duration, SystemTime},
+    };
+
     use windows::Win32::{
-        Foundation::{self, HWND, RECT},
-        Graphics::Dwm::{DWMWA_EXTENDED_FRAME_BOUNDS, DwmGetWindowAttribute},
-        System::Threading,
+        Foundation::{self, HWND, LPARAM, RECT},
+        Graphics::{
+            Dwm::{DWMWA_EXTENDED_FRAME_BOUNDS, DwmGetWindowAttribute},
+            Gdi::{MONITOR_DEFAULTTONEAREST, MonitorFromWindow},
+        },
+        System::{Diagnostics::ToolHelp, Threading},
         UI::WindowsAndMessaging::{self, GetWindowRect},
     };
+    use windows::core::PWSTR;
 
-    use crate::Bounds;
+    use crate::{
+        AppIdentity, Bounds, Error, Monitor, ProcessInfo, WindowId, WindowKind, WindowSnapshot,
+        WindowState,
+    };
 
     use super::WindowsError;
 
@@ -141,6 +289,20 @@ mod window {
             Ok(self.extended_frame_bounds()?.into())
         }
 
+        /// Returns the monitor returned by [`MonitorFromWindow`], which is the monitor
+        /// having the largest overlap with the window, or the nearest one.
+        ///
+        /// [`MonitorFromWindow`]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-monitorfromwindow
+        pub fn monitor(&self) -> Result<Option<Monitor>, WindowsError> {
+            let hmonitor = unsafe { MonitorFromWindow(self.0, MONITOR_DEFAULTTONEAREST) };
+
+            if hmonitor.is_invalid() {
+                return Ok(None);
+            }
+
+            super::monitor_info(hmonitor).map(Some)
+        }
+
         /// Returns the process ID of the owner of this window.
         pub fn owner_pid(&self) -> Result<u32, WindowsError> {
             let mut pid = 0;
@@ -191,10 +353,286 @@ mod window {
             Ok(String::from_utf16_lossy(&buffer[..length as usize]))
         }
 
+        /// Returns the full path of the executable file of the process that owns this window
+        /// by [`QueryFullProcessImageNameW`][qfpin].
+        ///
+        /// Unlike [`owner_name`](Self::owner_name), the path is not truncated and it only
+        /// requires `PROCESS_QUERY_LIMITED_INFORMATION` access to the process.
+        ///
+        /// [qfpin]: https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-queryfullprocessimagenamew
+        pub fn owner_executable(&self) -> Result<PathBuf, WindowsError> {
+            let pid = self.owner_pid()?;
+            let process_handle = unsafe {
+                Threading::OpenProcess(Threading::PROCESS_QUERY_LIMITED_INFORMATION, false, pid)?
+            };
+
+            // It is large enough for the extended-length paths.
+            let mut buffer = vec![0u16; 32768];
+            let mut length = buffer.len() as u32;
+            let result = unsafe {
+                Threading::QueryFullProcessImageNameW(
+                    process_handle,
+                    Threading::PROCESS_NAME_WIN32,
+                    PWSTR(buffer.as_mut_ptr()),
+                    &mut length,
+                )
+            };
+            let _ = unsafe { Foundation::CloseHandle(process_handle) };
+            result?;
+
+            Ok(OsString::from_wide(&buffer[..length as usize]).into())
+        }
+
+        /// Returns the information of the process that owns this window.
+        ///
+        /// The name and the executable are the same as [`owner_name`](Self::owner_name) and
+        /// [`owner_executable`](Self::owner_executable), the parent process ID is read from
+        /// a process snapshot of `CreateToolhelp32Snapshot` and the start time is read by
+        /// `GetProcessTimes`. The command line and the user ID are not provided.
+        pub fn owner_process(&self) -> Result<ProcessInfo, WindowsError> {
+            let pid = self.owner_pid()?;
+
+            let mut info = ProcessInfo::new(pid as _);
+            info.name = self.owner_name().ok();
+            info.executable = self.owner_executable().ok();
+            info.parent_pid = parent_process_id(pid).map(|pid| pid as _);
+            info.start_time = process_start_time(pid);
+
+            Ok(info)
+        }
+
+        /// Returns the identity of the application, which only has the
+        /// [executable](Self::owner_executable) on Windows.
+        pub fn app_id(&self) -> Result<AppIdentity, WindowsError> {
+            Ok(AppIdentity {
+                executable: Some(self.owner_executable()?),
+                ..Default::default()
+            })
+        }
+
+        /// Reads the title, the bounds and the owner of the window at once.
+        ///
+        /// The properties are read by separate system calls, so the window is checked by
+        /// `IsWindow` and `GetWindowThreadProcessId` before and after reading them.
+        /// If the window was destroyed in the meantime or its handle was reused by another
+        /// window, all the properties fail with [`Error::WindowNotFound`] instead of mixing
+        /// the properties of the different windows.
+        pub fn snapshot(&self) -> WindowSnapshot {
+            let id = WindowId(self.0);
+            let not_found = || WindowSnapshot::from_error(id, Error::WindowNotFound(id));
+
+            let Some(identity) = self.identity() else {
+                return not_found();
+            };
+
+            let title = self.title();
+            let bounds = self.visible_bounds();
+            let owner_name = self.owner_name();
+
+            if self.identity() != Some(identity) {
+                return not_found();
+            }
+
+            WindowSnapshot::from_results(
+                id,
+                title.map_err(Into::into),
+                bounds.map_err(Into::into),
+                Ok(identity.0 as _),
+                owner_name.map(Some).map_err(Into::into),
+            )
+        }
+
+        /// Returns the process ID and the thread ID which created the window,
+        /// or `None` if the window does not exist.
+        fn identity(&self) -> Option<(u32, u32)> {
+            if !unsafe { WindowsAndMessaging::IsWindow(Some(self.0)) }.as_bool() {
+                return None;
+            }
+
+            let mut pid = 0;
+            let thread =
+                unsafe { WindowsAndMessaging::GetWindowThreadProcessId(self.0, Some(&mut pid)) };
+
+            (thread != 0).then_some((pid, thread))
+        }
+
         /// Checks if the window is foreground.
         pub fn is_foreground(&self) -> bool {
             self.0 == unsafe { WindowsAndMessaging::GetForegroundWindow() }
         }
+
+        /// Checks if the window is visible and not minimized.
+        pub fn is_on_screen(&self) -> bool {
+            unsafe {
+                WindowsAndMessaging::IsWindowVisible(self.0).as_bool()
+                    && !WindowsAndMessaging::IsIconic(self.0).as_bool()
+            }
+        }
+
+        /// Returns the state of the window by `IsIconic`, `IsZoomed` and `IsWindowVisible`.
+        ///
+        /// The window is regarded as fullscreen when it is not minimized nor maximized
+        /// and its [`rect`](Self::rect) covers its whole [monitor](Self::monitor).
+        pub fn state(&self) -> Result<WindowState, WindowsError> {
+            let (minimized, maximized, visible) = unsafe {
+                (
+                    WindowsAndMessaging::IsIconic(self.0).as_bool(),
+                    WindowsAndMessaging::IsZoomed(self.0).as_bool(),
+                    WindowsAndMessaging::IsWindowVisible(self.0).as_bool(),
+                )
+            };
+            let fullscreen = !minimized
+                && !maximized
+                && match self.monitor()? {
+                    Some(monitor) => Bounds::from(self.rect()?).contains(&monitor.bounds),
+                    None => false,
+                };
+
+            let mut state = WindowState::empty();
+            state.set(WindowState::MINIMIZED, minimized);
+            state.set(WindowState::MAXIMIZED, maximized);
+            state.set(WindowState::FULLSCREEN, fullscreen);
+            state.set(WindowState::HIDDEN, !visible);
+            state.set(WindowState::ON_SCREEN, visible && !minimized);
+
+            Ok(state)
+        }
+
+        /// Returns the parent window by [`GetParent`][gp].
+        ///
+        /// It is the owner window for the pop-up windows such as the dialogs,
+        /// and `None` for the top-level windows without an owner.
+        ///
+        /// [gp]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getparent
+        pub fn parent(&self) -> Option<HWND> {
+            unsafe { WindowsAndMessaging::GetParent(self.0) }.ok()
+        }
+
+        /// Returns the owner window by [`GetWindow`][gw] with `GW_OWNER`.
+        ///
+        /// [gw]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getwindow
+        pub fn owner_window(&self) -> Option<HWND> {
+            unsafe { WindowsAndMessaging::GetWindow(self.0, WindowsAndMessaging::GW_OWNER) }.ok()
+        }
+
+        /// Returns the windows whose [parent](Self::parent) is the window,
+        /// i.e. the child windows and the owned pop-up windows.
+        pub fn children(&self) -> Result<Vec<HWND>, WindowsError> {
+            let mut handles = Vec::new();
+            let lparam = LPARAM(&mut handles as *mut Vec<HWND> as _);
+
+            // SAFETY: `Vec` should not be used during enumeration because it is used by mutable reference.
+            unsafe {
+                WindowsAndMessaging::EnumWindows(Some(super::enum_handles_callback), lparam)?;
+                // The return value is not used according to the documentation.
+                let _ = WindowsAndMessaging::EnumChildWindows(
+                    Some(self.0),
+                    Some(super::enum_handles_callback),
+                    lparam,
+                );
+            }
+
+            handles.retain(|&hwnd| Self::new(hwnd).parent() == Some(self.0));
+
+            Ok(handles)
+        }
+
+        /// Guesses the type of the window from its extended window styles.
+        ///
+        /// The windows with `WS_EX_TOOLWINDOW` are utility windows, or tooltips when they are
+        /// also topmost and transparent to the mouse. The windows with `WS_EX_DLGMODALFRAME`
+        /// or an owner window are dialogs unless they have `WS_EX_APPWINDOW`, which follows
+        /// the rule of the taskbar. The window returned by `GetShellWindow` is the desktop.
+        pub fn kind(&self) -> WindowKind {
+            use WindowsAndMessaging::{
+                GWL_EXSTYLE, WINDOW_EX_STYLE, WS_EX_APPWINDOW, WS_EX_DLGMODALFRAME,
+                WS_EX_TOOLWINDOW, WS_EX_TOPMOST, WS_EX_TRANSPARENT,
+            };
+
+            if self.0 == unsafe { WindowsAndMessaging::GetShellWindow() } {
+                return WindowKind::Desktop;
+            }
+
+            let style = WINDOW_EX_STYLE(unsafe {
+                WindowsAndMessaging::GetWindowLongW(self.0, GWL_EXSTYLE) as u32
+            });
+            let has_owner = self.owner_window().is_some();
+
+            if style.contains(WS_EX_TOOLWINDOW) {
+                if style.contains(WS_EX_TOPMOST) && style.contains(WS_EX_TRANSPARENT) {
+                    WindowKind::Tooltip
+                } else {
+                    WindowKind::Utility
+                }
+            } else if !style.contains(WS_EX_APPWINDOW)
+                && (style.contains(WS_EX_DLGMODALFRAME) || has_owner)
+            {
+                WindowKind::Dialog
+            } else {
+                WindowKind::Normal
+            }
+        }
+    }
+
+    /// Finds the parent process ID of the process in a snapshot of all the processes.
+    fn parent_process_id(pid: u32) -> Option<u32> {
+        let snapshot =
+            unsafe { ToolHelp::CreateToolhelp32Snapshot(ToolHelp::TH32CS_SNAPPROCESS, 0) }.ok()?;
+        let mut entry = ToolHelp::PROCESSENTRY32W {
+            dwSize: size_of::<ToolHelp::PROCESSENTRY32W>() as u32,
+            ..Default::default()
+        };
+
+        let mut parent_pid = None;
+        let mut result = unsafe { ToolHelp::Process32FirstW(snapshot, &mut entry) };
+        while result.is_ok() {
+            if entry.th32ProcessID == pid {
+                parent_pid = Some(entry.th32ParentProcessID);
+                break;
+            }
+
+            result = unsafe { ToolHelp::Process32NextW(snapshot, &mut entry) };
+        }
+        let _ = unsafe { Foundation::CloseHandle(snapshot) };
+
+        parent_pid
+    }
+
+    /// Reads the creation time of the process by `GetProcessTimes`.
+    fn process_start_time(pid: u32) -> Option<SystemTime> {
+        /// The seconds from 1601-01-01, the epoch of `FILETIME`, to the Unix epoch.
+        const UNIX_EPOCH_IN_FILETIME: u64 = 11_644_473_600;
+        /// The number of the 100-nanosecond intervals of `FILETIME` in a second.
+        const INTERVALS_PER_SECOND: u64 = 10_000_000;
+
+        let process_handle = unsafe {
+            Threading::OpenProcess(Threading::PROCESS_QUERY_LIMITED_INFORMATION, false, pid)
+        }
+        .ok()?;
+
+        let mut creation_time = Foundation::FILETIME::default();
+        let mut exit_time = Foundation::FILETIME::default();
+        let mut kernel_time = Foundation::FILETIME::default();
+        let mut user_time = Foundation::FILETIME::default();
+        let result = unsafe {
+            Threading::GetProcessTimes(
+                process_handle,
+                &mut creation_time,
+                &mut exit_time,
+                &mut kernel_time,
+                &mut user_time,
+            )
+        };
+        let _ = unsafe { Foundation::CloseHandle(process_handle) };
+        result.ok()?;
+
+        let intervals = (u64::from(creation_time.dwHighDateTime) << 32)
+            | u64::from(creation_time.dwLowDateTime);
+        let since_unix_epoch = Duration::from_secs(intervals / INTERVALS_PER_SECOND)
+            .checked_sub(Duration::from_secs(UNIX_EPOCH_IN_FILETIME))?
+            + Duration::from_nanos(intervals % INTERVALS_PER_SECOND * 100);
+
+        Some(SystemTime::UNIX_EPOCH + since_unix_epoch)
     }
 }
 
@@ -0,0 +1,1452 @@
+use std::sync::Arc;
+
+use x11rb::{
+    errors::ReplyError,
+    protocol::{ErrorKind, xproto::ConnectionExt as _},
+};
+
+use crate::{Error, Monitor, Window, WindowQuery};
+
+use super::linux::LinuxWindow;
+
+use connection::with_connection;
+
+pub use connection::{Atoms, X11Connection};
+pub use error::X11Error;
+pub use window::{X11PidSource, X11Window, X11WindowProperties};
+
+pub(crate) use notifier::X11Notifier;
+
+pub type X11Bounds = x11rb::protocol::xproto::Rectangle;
+pub type X11WindowId = x11rb::protocol::xproto::Window;
+
+/// Retrieves a window by its unique identifier.
+///
+/// It returns `None` if the window does not exist on the X server.
+pub fn get_window(id: X11WindowId) -> Result<Option<Window>, Error> {
+    Ok(with_connection(|connection| {
+        let attributes = connection.connection().get_window_attributes(id)?.reply();
+
+        match attributes {
+            Ok(_) => Ok(Some(new_window(connection, id))),
+            Err(ReplyError::X11Error(error)) if error.error_kind == ErrorKind::Window => Ok(None),
+            Err(error) => Err(error.into()),
+        }
+    })?)
+}
+
+/// Retrieves the active window by `_NET_ACTIVE_WINDOW`.
+///
+/// It returns `None` if no window is active or the window manager does not set the property.
+pub fn get_foreground_window() -> Result<Option<Window>, Error> {
+    Ok(with_connection(|connection| {
+        Ok(connection
+            .active_window()?
+            .map(|id| new_window(connection, id)))
+    })?)
+}
+
+/// Retrieves the monitors of the screen. See [`X11Connection::monitors`].
+pub fn get_monitors() -> Result<Vec<Monitor>, Error> {
+    Ok(with_connection(|connection| connection.monitors())?)
+}
+
+/// Retrieves a list of all top-level client windows in front-to-back order.
+///
+/// See [`X11Connection::client_list_stacking`] for how the windows and their order are determined.
+pub fn get_windows() -> Result<Vec<Window>, Error> {
+    Ok(with_connection(|connection| {
+        Ok(connection
+            .client_list_stacking()?
+            .into_iter()
+            .map(|id| new_window(connection, id))
+            .collect())
+    })?)
+}
+
+/// Retrieves the windows matching the query in front-to-back order.
+///
+/// When the query has conditions on the owner's process ID, the process IDs of all the
+/// windows are requested at once by [`X11Connection::owner_pids`], and the other conditions are only checked for the
+/// windows which passed them.
+pub fn find_windows(query: &WindowQuery) -> Result<Vec<Window>, Error> {
+    let (connection, ids) = with_connection(|connection| {
+        let mut ids = connection.client_list_stacking()?;
+
+        if query.has_owner_pid_condition() {
+            let pids = connection.owner_pids(&ids)?;
+
+            ids = ids
+                .into_iter()
+                .zip(pids)
+                .filter(|(_, pid)| query.matches_owner_pid(pid.map(|pid| pid as _)))
+                .map(|(id, _)| id)
+                .collect();
+        }
+
+        Ok((Arc::clone(connection), ids))
+    })?;
+
+    let windows = ids.into_iter().map(|id| new_window(&connection, id));
+
+    Ok(query.take_matches(windows, |window| query.matches_except_owner_pid(window)))
+}
+
+/// Retrieves the frontmost window matching the query at the point.
+///
+/// The window at the point is found by `XTranslateCoordinates` on the root window.
+/// If it does not match the query, the windows below it are looked for by their bounds.
+/// It returns `None` if the point is NaN or out of the range of the X11 coordinates.
+pub fn find_window_at(query: &WindowQuery, x: f64, y: f64) -> Result<Option<Window>, Error> {
+    let (Some(pixel_x), Some(pixel_y)) = (pixel_coordinate(x), pixel_coordinate(y)) else {
+        return Ok(None);
+    };
+
+    let (connection, top_level) = with_connection(|connection| {
+        Ok((
+            Arc::clone(connection),
+            connection.top_level_window_at(pixel_x, pixel_y)?,
+        ))
+    })?;
+    let Some(top_level) = top_level else {
+        return Ok(None);
+    };
+
+    let ids = connection.client_list_stacking()?;
+    for &id in &ids {
+        let ancestor = match connection.top_level_ancestor(id) {
+            Ok(ancestor) => ancestor,
+            // The window was destroyed after the client list was read.
+            Err(X11Error::Reply(ReplyError::X11Error(error)))
+                if error.error_kind == ErrorKind::Window =>
+            {
+                continue;
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        if ancestor == top_level {
+            let window = new_window(&connection, id);
+            if query.matches(&window) {
+                return Ok(Some(window));
+            }
+            break;
+        }
+    }
+
+    let windows = ids.into_iter().map(|id| new_window(&connection, id));
+
+    Ok(query.take_match_at(windows, x, y))
+}
+
+/// Converts the coordinate to the pixel containing it.
+/// It is `None` if the value is NaN or out of the range of `i16`.
+fn pixel_coordinate(value: f64) -> Option<i16> {
+    let value = value.floor();
+    (value >= i16::MIN.into() && value <= i16::MAX.into()).then_some(value as i16)
+}
+
+fn new_window(connection: &Arc<X11Connection>, id: X11WindowId) -> Window {
+    Window::new(LinuxWindow::X11(X11Window::new(Arc::clone(connection), id)))
+}
+
+pub mod connection {
+    use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+    use x11rb::{
+        connection::{Connection, RequestConnection},
+        cookie::Cookie,
+        errors::ReplyError,
+        protocol::{
+            randr::{self, ConnectionExt as _},
+            res::{self, ClientIdMask, ClientIdSpec, ConnectionExt as _},
+            xproto::{AtomEnum, ConnectionExt as _, GetPropertyReply, MapState},
+        },
+        rust_connection::RustConnection,
+    };
+
+    use crate::{Bounds, CoordinateSpace, Monitor};
+
+    use super::{X11Error, X11WindowId};
+
+    x11rb::atom_manager! {
+        /// The atoms used to read the window properties.
+        pub Atoms: AtomsCookie {
+            _NET_ACTIVE_WINDOW,
+            _NET_CLIENT_LIST,
+            _NET_CLIENT_LIST_STACKING,
+            _NET_CURRENT_DESKTOP,
+            _NET_FRAME_EXTENTS,
+            _NET_WM_NAME,
+            _NET_WM_PID,
+            _NET_WM_STATE,
+            _NET_WM_STATE_FULLSCREEN,
+            _NET_WM_STATE_HIDDEN,
+            _NET_WM_STATE_MAXIMIZED_HORZ,
+            _NET_WM_STATE_MAXIMIZED_VERT,
+            _NET_WM_WINDOW_TYPE,
+            _NET_WM_WINDOW_TYPE_COMBO,
+            _NET_WM_WINDOW_TYPE_DESKTOP,
+            _NET_WM_WINDOW_TYPE_DIALOG,
+            _NET_WM_WINDOW_TYPE_DND,
+            _NET_WM_WINDOW_TYPE_DOCK,
+            _NET_WM_WINDOW_TYPE_DROPDOWN_MENU,
+            _NET_WM_WINDOW_TYPE_MENU,
+            _NET_WM_WINDOW_TYPE_NORMAL,
+            _NET_WM_WINDOW_TYPE_NOTIFICATION,
+            _NET_WM_WINDOW_TYPE_POPUP_MENU,
+            _NET_WM_WINDOW_TYPE_SPLASH,
+            _NET_WM_WINDOW_TYPE_TOOLBAR,
+            _NET_WM_WINDOW_TYPE_TOOLTIP,
+            _NET_WM_WINDOW_TYPE_UTILITY,
+            _NET_WORKAREA,
+            UTF8_STRING,
+            WM_STATE,
+        }
+    }
+
+    static SHARED: Mutex<Option<Arc<X11Connection>>> = Mutex::new(None);
+
+    /// Runs `f` with the connection kept for the whole process, connecting to the X server
+    /// if needed, so the atoms are not interned again for each call.
+    /// The connection is dropped when `f` fails because of the connection, so the next call
+    /// reconnects.
+    pub(crate) fn with_connection<R>(
+        f: impl FnOnce(&Arc<X11Connection>) -> Result<R, X11Error>,
+    ) -> Result<R, X11Error> {
+        let connection = {
+            let mut guard = SHARED.lock().unwrap_or_else(PoisonError::into_inner);
+            match guard.as_ref() {
+                Some(connection) => Arc::clone(connection),
+                None => Arc::clone(guard.insert(X11Connection::connect()?)),
+            }
+        };
+
+        let result = f(&connection);
+        if let Err(X11Error::Connection(_) | X11Error::Reply(ReplyError::ConnectionError(_))) =
+            &result
+        {
+            let mut guard = SHARED.lock().unwrap_or_else(PoisonError::into_inner);
+            if guard
+                .as_ref()
+                .is_some_and(|shared| Arc::ptr_eq(shared, &connection))
+            {
+                *guard = None;
+            }
+        }
+
+        result
+    }
+
+    /// A connection to the X server shared by the windows retrieved from it.
+    #[derive(Debug)]
+    pub struct X11Connection {
+        connection: RustConnection,
+        root: X11WindowId,
+        atoms: Atoms,
+        supports_res: OnceLock<bool>,
+    }
+
+    impl X11Connection {
+        /// Connects to the X server specified by the `DISPLAY` environment variable.
+        ///
+        /// The functions of this module do not call it each time but share a single
+        /// connection kept for the whole process.
+        pub fn connect() -> Result<Arc<Self>, X11Error> {
+            let (connection, screen) = RustConnection::connect(None)?;
+            let root = connection.setup().roots[screen].root;
+            let atoms = Atoms::new(&connection)?.reply()?;
+
+            Ok(Arc::new(Self {
+                connection,
+                root,
+                atoms,
+                supports_res: OnceLock::new(),
+            }))
+        }
+
+        /// Returns the underlying connection.
+        pub fn connection(&self) -> &RustConnection {
+            &self.connection
+        }
+
+        /// Returns the root window of the default screen.
+        pub fn root(&self) -> X11WindowId {
+            self.root
+        }
+
+        /// Returns the atoms interned on this connection.
+        pub fn atoms(&self) -> &Atoms {
+            &self.atoms
+        }
+
+        /// Retrieves the whole value of a window property.
+        pub fn property(
+            &self,
+            window: X11WindowId,
+            property: impl Into<u32>,
+            type_: impl Into<u32>,
+        ) -> Result<GetPropertyReply, X11Error> {
+            Ok(self.property_cookie(window, property, type_)?.reply()?)
+        }
+
+        /// Sends the request of [`property`](Self::property) without waiting for the reply.
+        /// It is useful to send several requests before waiting for their replies.
+        pub fn property_cookie(
+            &self,
+            window: X11WindowId,
+            property: impl Into<u32>,
+            type_: impl Into<u32>,
+        ) -> Result<Cookie<'_, RustConnection, GetPropertyReply>, X11Error> {
+            Ok(self
+                .connection
+                .get_property(false, window, property, type_, 0, u32::MAX)?)
+        }
+
+        /// Retrieves the active window from `_NET_ACTIVE_WINDOW` of the root window.
+        /// It is `None` if no window is active or the property is not set.
+        pub fn active_window(&self) -> Result<Option<X11WindowId>, X11Error> {
+            let reply =
+                self.property(self.root, self.atoms._NET_ACTIVE_WINDOW, AtomEnum::WINDOW)?;
+
+            Ok(reply
+                .value32()
+                .and_then(|mut windows| windows.next())
+                .filter(|&window| window != x11rb::NONE))
+        }
+
+        /// Retrieves the top-level client windows from `_NET_CLIENT_LIST` of the root window.
+        pub fn client_list(&self) -> Result<Vec<X11WindowId>, X11Error> {
+            let reply = self.property(self.root, self.atoms._NET_CLIENT_LIST, AtomEnum::WINDOW)?;
+
+            match reply.value32() {
+                Some(windows) => Ok(windows.collect()),
+                None => Err(X11Error::MissingProperty("_NET_CLIENT_LIST")),
+            }
+        }
+
+        /// Retrieves the top-level client windows in front-to-back order.
+        ///
+        /// It uses `_NET_CLIENT_LIST_STACKING` of the root window if the window manager
+        /// provides it. Otherwise, the order is determined from the children of the root
+        /// window by `XQueryTree`, which the X server returns in bottom-to-top order.
+        /// If `_NET_CLIENT_LIST` is not provided either, the viewable children of
+        /// the root window are returned.
+        pub fn client_list_stacking(&self) -> Result<Vec<X11WindowId>, X11Error> {
+            let reply = self.property(
+                self.root,
+                self.atoms._NET_CLIENT_LIST_STACKING,
+                AtomEnum::WINDOW,
+            )?;
+
+            if let Some(windows) = reply.value32() {
+                let mut windows = windows.collect::<Vec<_>>();
+                windows.reverse();
+                return Ok(windows);
+            }
+
+            let mut children = self.connection.query_tree(self.root)?.reply()?.children;
+            children.reverse();
+
+            match self.client_list() {
+                Ok(clients) => {
+                    let mut clients = clients
+                        .into_iter()
+                        .map(|client| Ok((self.top_level_ancestor(client)?, client)))
+                        .collect::<Result<Vec<_>, X11Error>>()?;
+                    clients.sort_by_key(|(ancestor, _)| {
+                        children
+                            .iter()
+                            .position(|child| child == ancestor)
+                            .unwrap_or(usize::MAX)
+                    });
+
+                    Ok(clients.into_iter().map(|(_, client)| client).collect())
+                }
+                Err(X11Error::MissingProperty(_)) => self.viewable_windows(children),
+                Err(error) => Err(error),
+            }
+        }
+
+        /// Retrieves the process IDs of the windows' owners at once.
+        ///
+        /// They are read from `_NET_WM_PID`, and the windows which do not set it are
+        /// looked up by [`res_client_pids`](Self::res_client_pids).
+        /// Each value is `None` if the process ID could not be resolved in either way.
+        pub fn owner_pids(&self, windows: &[X11WindowId]) -> Result<Vec<Option<u32>>, X11Error> {
+            let cookies = windows
+                .iter()
+                .map(|&window| {
+                    self.property_cookie(window, self.atoms._NET_WM_PID, AtomEnum::CARDINAL)
+                })
+                .collect::<Result<Vec<_>, X11Error>>()?;
+
+            let mut pids = cookies
+                .into_iter()
+                .map(|cookie| {
+                    let reply = cookie.reply().ok()?;
+                    super::window::parse_pid(&reply).ok()
+                })
+                .collect::<Vec<_>>();
+
+            let (missing, missing_windows): (Vec<_>, Vec<_>) = windows
+                .iter()
+                .zip(&pids)
+                .enumerate()
+                .filter(|(_, (_, pid))| pid.is_none())
+                .map(|(index, (&window, _))| (index, window))
+                .unzip();
+
+            if !missing.is_empty() {
+                let res_pids = self.res_client_pids(&missing_windows)?;
+                for (index, pid) in missing.into_iter().zip(res_pids) {
+                    pids[index] = pid;
+                }
+            }
+
+            Ok(pids)
+        }
+
+        /// Retrieves the process IDs of the clients which created the windows
+        /// by `XResQueryClientIds` of the X-Resource extension.
+        ///
+        /// Unlike `_NET_WM_PID`, it is reported by the X server, so it is available even
+        /// when the client does not set the property. Each value is `None` if the client
+        /// is not connected from this machine or the window does not exist, and all of them
+        /// are `None` if the X server does not support X-Resource 1.2.
+        pub fn res_client_pids(
+            &self,
+            windows: &[X11WindowId],
+        ) -> Result<Vec<Option<u32>>, X11Error> {
+            if !self.supports_res()? {
+                return Ok(vec![None; windows.len()]);
+            }
+
+            let cookies = windows
+                .iter()
+                .map(|&window| {
+                    let spec = ClientIdSpec {
+                        client: window,
+                        mask: ClientIdMask::LOCAL_CLIENT_PID,
+                    };
+                    self.connection.res_query_client_ids(&[spec])
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(cookies
+                .into_iter()
+                .map(|cookie| {
+                    cookie
+                        .reply()
+                        .ok()?
+                        .ids
+                        .into_iter()
+                        .find(|id| id.spec.mask.contains(ClientIdMask::LOCAL_CLIENT_PID))?
+                        .value
+                        .first()
+                        .copied()
+                })
+                .collect())
+        }
+
+        /// Returns whether the X server supports X-Resource 1.2, which provides
+        /// `XResQueryClientIds`. The result is cached in the connection.
+        fn supports_res(&self) -> Result<bool, X11Error> {
+            if let Some(&supported) = self.supports_res.get() {
+                return Ok(supported);
+            }
+
+            let supported = if self
+                .connection
+                .extension_information(res::X11_EXTENSION_NAME)?
+                .is_some()
+            {
+                let version = self.connection.res_query_version(1, 2)?.reply()?;
+                (version.server_major, version.server_minor) >= (1, 2)
+            } else {
+                false
+            };
+
+            Ok(*self.supports_res.get_or_init(|| supported))
+        }
+
+        /// Returns the topmost viewable child of the root window which contains the point
+        /// by `XTranslateCoordinates`. It is the frame window when the window manager
+        /// reparents the clients.
+        pub fn top_level_window_at(&self, x: i16, y: i16) -> Result<Option<X11WindowId>, X11Error> {
+            let reply = self
+                .connection
+                .translate_coordinates(self.root, self.root, x, y)?
+                .reply()?;
+
+            Ok((reply.child != x11rb::NONE).then_some(reply.child))
+        }
+
+        /// Retrieves the monitors of the screen.
+        ///
+        /// They are the active monitors of RandR 1.5. If the X server does not support it,
+        /// the whole screen is returned as the only monitor.
+        /// The work area of each monitor is the part of its bounds within `_NET_WORKAREA`
+        /// of the current desktop, and the scale factor is `Xft.dpi` divided by 96.
+        pub fn monitors(&self) -> Result<Vec<Monitor>, X11Error> {
+            let work_area = self.work_area()?;
+            let scale_factor = self.scale_factor()?;
+
+            let monitors = match self.randr_monitors()? {
+                Some(monitors) => monitors,
+                None => vec![self.screen_monitor()],
+            };
+
+            Ok(monitors
+                .into_iter()
+                .map(|mut monitor| {
+                    if let Some(work_area) = work_area
+                        .as_ref()
+                        .and_then(|work_area| monitor.bounds.intersect(work_area))
+                    {
+                        monitor.work_area = work_area;
+                    }
+                    monitor.scale_factor = scale_factor;
+                    monitor
+                })
+                .collect())
+        }
+
+        /// Retrieves the active monitors by RandR.
+        /// It is `None` if the X server does not support RandR 1.5.
+        fn randr_monitors(&self) -> Result<Option<Vec<Monitor>>, X11Error> {
+            if self
+                .connection
+                .extension_information(randr::X11_EXTENSION_NAME)?
+                .is_none()
+            {
+                return Ok(None);
+            }
+
+            let version = self.connection.randr_query_version(1, 5)?.reply()?;
+            if (version.major_version, version.minor_version) < (1, 5) {
+                return Ok(None);
+            }
+
+            let monitors = self
+                .connection
+                .randr_get_monitors(self.root, true)?
+                .reply()?
+                .monitors;
+            let names = monitors
+                .iter()
+                .map(|monitor| self.connection.get_atom_name(monitor.name))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let monitors = monitors
+                .into_iter()
+                .zip(names)
+                .map(|(monitor, name)| {
+                    let bounds = Bounds::new(
+                        monitor.x.into(),
+                        monitor.y.into(),
+                        monitor.width.into(),
+                        monitor.height.into(),
+                    );
+
+                    Monitor {
+                        id: monitor.name.into(),
+                        name: name
+                            .reply()
+                            .ok()
+                            .map(|name| String::from_utf8_lossy(&name.name).into_owned()),
+                        work_area: bounds.clone(),
+                        bounds,
+                        scale_factor: 1.0,
+                        is_primary: monitor.primary,
+                        coordinate_space: CoordinateSpace::Physical,
+                    }
+                })
+                .collect();
+
+            Ok(Some(monitors))
+        }
+
+        /// Returns the whole screen as a monitor.
+        fn screen_monitor(&self) -> Monitor {
+            let screen = self
+                .connection
+                .setup()
+                .roots
+                .iter()
+                .find(|screen| screen.root == self.root)
+                .expect("the root window belongs to a screen");
+            let bounds = Bounds::new(
+                0.0,
+                0.0,
+                screen.width_in_pixels.into(),
+                screen.height_in_pixels.into(),
+            );
+
+            Monitor {
+                id: self.root.into(),
+                name: None,
+                work_area: bounds.clone(),
+                bounds,
+                scale_factor: 1.0,
+                is_primary: true,
+                coordinate_space: CoordinateSpace::Physical,
+            }
+        }
+
+        /// Retrieves the work area of the current desktop from `_NET_WORKAREA`.
+        fn work_area(&self) -> Result<Option<Bounds>, X11Error> {
+            let work_areas =
+                self.property_cookie(self.root, self.atoms._NET_WORKAREA, AtomEnum::CARDINAL)?;
+            let desktop = self.property_cookie(
+                self.root,
+                self.atoms._NET_CURRENT_DESKTOP,
+                AtomEnum::CARDINAL,
+            )?;
+
+            let work_areas = work_areas.reply()?;
+            let desktop = desktop
+                .reply()?
+                .value32()
+                .and_then(|mut desktop| desktop.next())
+                .unwrap_or(0) as usize;
+
+            Ok(work_areas.value32().and_then(|values| {
+                let values = values.skip(desktop * 4).take(4).collect::<Vec<_>>();
+                let [x, y, width, height] = values[..] else {
+                    return None;
+                };
+
+                Some(Bounds::new(x.into(), y.into(), width.into(), height.into()))
+            }))
+        }
+
+        /// Returns `Xft.dpi` in `RESOURCE_MANAGER` of the root window divided by 96,
+        /// or `1.0` if it is not set.
+        fn scale_factor(&self) -> Result<f64, X11Error> {
+            let reply = self.property(self.root, AtomEnum::RESOURCE_MANAGER, AtomEnum::STRING)?;
+
+            let dpi = String::from_utf8_lossy(&reply.value)
+                .lines()
+                .find_map(|line| line.strip_prefix("Xft.dpi:")?.trim().parse::<f64>().ok());
+
+            Ok(dpi.map_or(1.0, |dpi| dpi / 96.0))
+        }
+
+        /// Returns the child of the root window which contains the window.
+        /// It is the frame window when the window manager reparents the clients.
+        pub fn top_level_ancestor(&self, mut window: X11WindowId) -> Result<X11WindowId, X11Error> {
+            loop {
+                let parent = self.connection.query_tree(window)?.reply()?.parent;
+
+                if parent == self.root || parent == x11rb::NONE {
+                    return Ok(window);
+                }
+
+                window = parent;
+            }
+        }
+
+        fn viewable_windows(
+            &self,
+            windows: Vec<X11WindowId>,
+        ) -> Result<Vec<X11WindowId>, X11Error> {
+            let cookies = windows
+                .into_iter()
+                .map(|window| Ok((window, self.connection.get_window_attributes(window)?)))
+                .collect::<Result<Vec<_>, X11Error>>()?;
+
+            let mut viewable = Vec::new();
+            for (window, cookie) in cookies {
+                if cookie.reply()?.map_state == MapState::VIEWABLE {
+                    viewable.push(window);
+                }
+            }
+
+            Ok(viewable)
+        }
+    }
+}
+
+pub mod window {
+    use std::{path::PathBuf, sync::Arc};
+
+    use x11rb::{
+        cookie::Cookie,
+        protocol::xproto::{
+            AtomEnum, ConnectionExt as _, GetGeometryReply, GetPropertyReply, MapState,
+            TranslateCoordinatesReply,
+        },
+        rust_connection::RustConnection,
+    };
+
+    use crate::{AppIdentity, Bounds, Monitor, WindowKind, WindowState};
+
+    use super::{Atoms, X11Bounds, X11Connection, X11Error, X11WindowId};
+
+    /// Represents a top-level window on the X server.
+    #[derive(Clone, Debug)]
+    pub struct X11Window {
+        connection: Arc<X11Connection>,
+        id: X11WindowId,
+    }
+
+    impl X11Window {
+        /// Creates a new `X11Window` from a connection and a window ID.
+        ///
+        /// # Warning
+        /// You must ensure that the `id` is a valid window on the connection.
+        /// You can use [`get_window`][super::get_window] to safely retrieve a `X11Window`.
+        pub fn new(connection: Arc<X11Connection>, id: X11WindowId) -> Self {
+            Self { connection, id }
+        }
+
+        /// Returns the connection this window was retrieved from.
+        pub fn connection(&self) -> &Arc<X11Connection> {
+            &self.connection
+        }
+
+        /// Returns the window ID. It is called XID.
+        pub fn id(&self) -> X11WindowId {
+            self.id
+        }
+
+        /// Returns the title of the window.
+        ///
+        /// It reads `_NET_WM_NAME` first and falls back to `WM_NAME`.
+        pub fn title(&self) -> Result<Option<String>, X11Error> {
+            let [net_wm_name, wm_name] = self.title_cookies()?;
+
+            Ok(parse_title(
+                self.connection.atoms(),
+                &net_wm_name.reply()?,
+                &wm_name.reply()?,
+            ))
+        }
+
+        /// Returns the frame extents set by the window manager by `_NET_FRAME_EXTENTS`.
+        /// The order of the values is left, right, top and bottom.
+        pub fn frame_extents(&self) -> Result<Option<[u32; 4]>, X11Error> {
+            Ok(parse_frame_extents(&self.frame_extents_cookie()?.reply()?))
+        }
+
+        /// Returns the rectangle of the client area in the root window coordinates.
+        ///
+        /// It does not include the decorations drawn by the window manager.
+        pub fn rect(&self) -> Result<X11Bounds, X11Error> {
+            let connection = self.connection.connection();
+            let geometry = connection.get_geometry(self.id)?;
+            let origin = connection.translate_coordinates(self.id, self.connection.root(), 0, 0)?;
+
+            Ok(parse_rect(&geometry.reply()?, &origin.reply()?))
+        }
+
+        /// Returns the bounds of the window.
+        /// This will return [`rect`](Self::rect) value extended by
+        /// [`frame_extents`](Self::frame_extents) if the window manager sets them.
+        pub fn bounds(&self) -> Result<Bounds, X11Error> {
+            Ok(extend_by_frame(self.rect()?, self.frame_extents()?).into())
+        }
+
+        /// Returns the process ID of the window's owner.
+        /// See [`owner_pid_with_source`](Self::owner_pid_with_source) for how it is resolved.
+        pub fn owner_pid(&self) -> Result<u32, X11Error> {
+            self.owner_pid_with_source().map(|(pid, _)| pid)
+        }
+
+        /// Returns the process ID of the window's owner and how it was resolved.
+        ///
+        /// It is read from `_NET_WM_PID`. If the client does not set the property,
+        /// the X server is asked by [`X11Connection::res_client_pids`] instead,
+        /// which only works for the clients connected from this machine.
+        pub fn owner_pid_with_source(&self) -> Result<(u32, X11PidSource), X11Error> {
+            self.resolve_pid(&self.pid_cookie()?.reply()?)
+        }
+
+        /// Returns the process ID of the window's owner if it runs on this machine.
+        ///
+        /// `_NET_WM_PID` is only trusted when `WM_CLIENT_MACHINE` is the name of this machine,
+        /// since the process ID of a remote client refers to an unrelated local process.
+        /// Otherwise, the process ID reported by [`X11Connection::res_client_pids`] is used,
+        /// which is only available for local clients. It is `None` if the client is remote
+        /// or could not be determined to be local.
+        pub fn local_owner_pid(&self) -> Result<Option<u32>, X11Error> {
+            let pid = self.pid_cookie()?;
+            let client_machine = self.client_machine_cookie()?;
+
+            let pid = self.resolve_pid(&pid.reply()?)?;
+            self.local_pid(pid, &client_machine.reply()?)
+        }
+
+        /// Returns the name of the process that owns the window.
+        ///
+        /// It is read from `/proc/<pid>/comm` with [`local_owner_pid`](Self::local_owner_pid),
+        /// so it is `None` when the process is not running on this machine.
+        pub fn owner_name(&self) -> Result<Option<String>, X11Error> {
+            Ok(self.local_owner_pid()?.and_then(process_name))
+        }
+
+        /// Returns the instance name and the class of `WM_CLASS`.
+        pub fn wm_class(&self) -> Result<Option<(String, String)>, X11Error> {
+            let reply = self
+                .connection
+                .property(self.id, AtomEnum::WM_CLASS, AtomEnum::STRING)?;
+
+            Ok(parse_wm_class(&reply))
+        }
+
+        /// Returns the identity of the application from `WM_CLASS` and the owner's process ID.
+        ///
+        /// The executable is read from `/proc/<pid>/exe` with
+        /// [`local_owner_pid`](Self::local_owner_pid), so it is `None` when the process
+        /// is not running on this machine or its process ID could not be resolved.
+        pub fn app_id(&self) -> Result<AppIdentity, X11Error> {
+            let wm_class =
+                self.connection
+                    .property_cookie(self.id, AtomEnum::WM_CLASS, AtomEnum::STRING)?;
+            let pid = self.pid_cookie()?;
+            let client_machine = self.client_machine_cookie()?;
+
+            let (instance, class) = parse_wm_class(&wm_class.reply()?).unzip();
+            let client_machine = client_machine.reply()?;
+            let executable = self
+                .resolve_pid(&pid.reply()?)
+                .and_then(|pid| self.local_pid(pid, &client_machine))
+                .ok()
+                .flatten()
+                .and_then(process_executable);
+
+            Ok(AppIdentity {
+                id: class,
+                instance,
+                executable,
+            })
+        }
+
+        /// Returns the monitor which has the largest overlap with the window.
+        pub fn monitor(&self) -> Result<Option<Monitor>, X11Error> {
+            let bounds = self.bounds()?;
+
+            Ok(crate::monitor::monitor_for_bounds(
+                self.connection.monitors()?,
+                &bounds,
+            ))
+        }
+
+        /// Checks if the window is the active window set by the window manager
+        /// to `_NET_ACTIVE_WINDOW`.
+        pub fn is_active(&self) -> Result<bool, X11Error> {
+            Ok(self.connection.active_window()? == Some(self.id))
+        }
+
+        /// Checks if the window is viewable, i.e. it and all its ancestors are mapped.
+        ///
+        /// The window managers usually unmap the windows which are minimized
+        /// or on the other workspaces.
+        pub fn is_viewable(&self) -> Result<bool, X11Error> {
+            let attributes = self
+                .connection
+                .connection()
+                .get_window_attributes(self.id)?
+                .reply()?;
+
+            Ok(attributes.map_state == MapState::VIEWABLE)
+        }
+
+        /// Reads the state of the window.
+        ///
+        /// [`MINIMIZED`](WindowState::MINIMIZED) is the `IconicState` of `WM_STATE`,
+        /// [`ON_SCREEN`](WindowState::ON_SCREEN) is [`is_viewable`](Self::is_viewable) and
+        /// the other flags are read from `_NET_WM_STATE`.
+        pub fn state(&self) -> Result<WindowState, X11Error> {
+            /// The value of `WM_STATE` for the iconified windows defined by ICCCM.
+            const ICONIC_STATE: u32 = 3;
+
+            let atoms = self.connection.atoms();
+            let net_wm_state =
+                self.connection
+                    .property_cookie(self.id, atoms._NET_WM_STATE, AtomEnum::ATOM)?;
+            let wm_state =
+                self.connection
+                    .property_cookie(self.id, atoms.WM_STATE, atoms.WM_STATE)?;
+            let attributes = self
+                .connection
+                .connection()
+                .get_window_attributes(self.id)?;
+
+            let net_wm_state = net_wm_state
+                .reply()?
+                .value32()
+                .map(Iterator::collect::<Vec<_>>)
+                .unwrap_or_default();
+            let wm_state = wm_state
+                .reply()?
+                .value32()
+                .and_then(|mut state| state.next());
+            let map_state = attributes.reply()?.map_state;
+            let has = |atom| net_wm_state.contains(&atom);
+
+            let mut state = WindowState::empty();
+            state.set(WindowState::MINIMIZED, wm_state == Some(ICONIC_STATE));
+            state.set(
+                WindowState::MAXIMIZED,
+                has(atoms._NET_WM_STATE_MAXIMIZED_VERT) && has(atoms._NET_WM_STATE_MAXIMIZED_HORZ),
+            );
+            state.set(WindowState::FULLSCREEN, has(atoms._NET_WM_STATE_FULLSCREEN));
+            state.set(WindowState::HIDDEN, has(atoms._NET_WM_STATE_HIDDEN));
+            state.set(WindowState::ON_SCREEN, map_state == MapState::VIEWABLE);
+
+            Ok(state)
+        }
+
+        /// Reads the type of the window from `_NET_WM_WINDOW_TYPE`.
+        ///
+        /// The first type known to this crate is used because the types are listed
+        /// in order of preference. When no known type is set, the window is
+        /// [`Dialog`](WindowKind::Dialog) if it has `WM_TRANSIENT_FOR` and
+        /// [`Normal`](WindowKind::Normal) otherwise, as defined by EWMH.
+        pub fn kind(&self) -> Result<WindowKind, X11Error> {
+            let atoms = self.connection.atoms();
+            let window_type = self.connection.property_cookie(
+                self.id,
+                atoms._NET_WM_WINDOW_TYPE,
+                AtomEnum::ATOM,
+            )?;
+            let transient_for = self.connection.property_cookie(
+                self.id,
+                AtomEnum::WM_TRANSIENT_FOR,
+                AtomEnum::WINDOW,
+            )?;
+
+            let known_types = [
+                (atoms._NET_WM_WINDOW_TYPE_NORMAL, WindowKind::Normal),
+                (atoms._NET_WM_WINDOW_TYPE_DIALOG, WindowKind::Dialog),
+                (atoms._NET_WM_WINDOW_TYPE_UTILITY, WindowKind::Utility),
+                (atoms._NET_WM_WINDOW_TYPE_TOOLBAR, WindowKind::Utility),
+                (atoms._NET_WM_WINDOW_TYPE_MENU, WindowKind::Menu),
+                (atoms._NET_WM_WINDOW_TYPE_DROPDOWN_MENU, WindowKind::Menu),
+                (atoms._NET_WM_WINDOW_TYPE_POPUP_MENU, WindowKind::Menu),
+                (atoms._NET_WM_WINDOW_TYPE_COMBO, WindowKind::Menu),
+                (atoms._NET_WM_WINDOW_TYPE_TOOLTIP, WindowKind::Tooltip),
+                (
+                    atoms._NET_WM_WINDOW_TYPE_NOTIFICATION,
+                    WindowKind::Notification,
+                ),
+                (atoms._NET_WM_WINDOW_TYPE_SPLASH, WindowKind::Splash),
+                (atoms._NET_WM_WINDOW_TYPE_DOCK, WindowKind::Dock),
+                (atoms._NET_WM_WINDOW_TYPE_DESKTOP, WindowKind::Desktop),
+                (atoms._NET_WM_WINDOW_TYPE_DND, WindowKind::Other),
+            ];
+            let kind = window_type.reply()?.value32().and_then(|mut types| {
+                types.find_map(|atom| {
+                    known_types
+                        .iter()
+                        .find(|(known, _)| *known == atom)
+                        .map(|&(_, kind)| kind)
+                })
+            });
+
+            if let Some(kind) = kind {
+                return Ok(kind);
+            }
+
+            Ok(if transient_for.reply()?.value_len > 0 {
+                WindowKind::Dialog
+            } else {
+                WindowKind::Normal
+            })
+        }
+
+        /// Returns the window set to `WM_TRANSIENT_FOR`, e.g. the main window of a dialog.
+        pub fn owner_window(&self) -> Result<Option<X11WindowId>, X11Error> {
+            let reply =
+                self.connection
+                    .property(self.id, AtomEnum::WM_TRANSIENT_FOR, AtomEnum::WINDOW)?;
+
+            Ok(parse_transient_for(&reply, self.connection.root()))
+        }
+
+        /// Returns the parent window.
+        ///
+        /// It is the [owner window](Self::owner_window) if the window has it. Otherwise, it is
+        /// the parent by `XQueryTree`, except for the top-level client windows, which have
+        /// `WM_STATE` and whose parent is the root window or the frame of the window manager.
+        pub fn parent(&self) -> Result<Option<X11WindowId>, X11Error> {
+            let atoms = self.connection.atoms();
+            let root = self.connection.root();
+            let transient_for = self.connection.property_cookie(
+                self.id,
+                AtomEnum::WM_TRANSIENT_FOR,
+                AtomEnum::WINDOW,
+            )?;
+            let wm_state =
+                self.connection
+                    .property_cookie(self.id, atoms.WM_STATE, atoms.WM_STATE)?;
+            let tree = self.connection.connection().query_tree(self.id)?;
+
+            if let Some(owner) = parse_transient_for(&transient_for.reply()?, root) {
+                return Ok(Some(owner));
+            }
+
+            let parent = tree.reply()?.parent;
+            let is_client = wm_state.reply()?.value_len > 0;
+
+            Ok((!is_client && parent != root && parent != x11rb::NONE).then_some(parent))
+        }
+
+        /// Returns the windows whose [parent](Self::parent) is the window, i.e. the children
+        /// by `XQueryTree` from bottom to top followed by the client windows whose
+        /// [owner window](Self::owner_window) is the window.
+        pub fn children(&self) -> Result<Vec<X11WindowId>, X11Error> {
+            let root = self.connection.root();
+            let tree = self.connection.connection().query_tree(self.id)?;
+            let clients = self.connection.client_list_stacking()?;
+            let cookies = clients
+                .iter()
+                .map(|&client| {
+                    self.connection.property_cookie(
+                        client,
+                        AtomEnum::WM_TRANSIENT_FOR,
+                        AtomEnum::WINDOW,
+                    )
+                })
+                .collect::<Result<Vec<_>, X11Error>>()?;
+
+            let mut children = tree.reply()?.children;
+            for (client, cookie) in clients.into_iter().zip(cookies) {
+                // The windows which were closed in the meantime are skipped.
+                let owner = cookie
+                    .reply()
+                    .ok()
+                    .and_then(|reply| parse_transient_for(&reply, root));
+
+                if owner == Some(self.id) && !children.contains(&client) {
+                    children.push(client);
+                }
+            }
+
+            Ok(children)
+        }
+
+        /// Reads the title, the bounds, the owner's process ID and its name at once.
+        ///
+        /// All the requests are sent before waiting for the replies, so they are answered
+        /// in a single round trip unless the X-Resource extension has to be asked for
+        /// the process ID.
+        pub fn properties(&self) -> Result<X11WindowProperties, X11Error> {
+            let connection = self.connection.connection();
+
+            let [net_wm_name, wm_name] = self.title_cookies()?;
+            let geometry = connection.get_geometry(self.id)?;
+            let origin = connection.translate_coordinates(self.id, self.connection.root(), 0, 0)?;
+            let frame_extents = self.frame_extents_cookie()?;
+            let pid = self.pid_cookie()?;
+            let client_machine = self.client_machine_cookie()?;
+
+            let title = net_wm_name.reply().and_then(|net_wm_name| {
+                Ok(parse_title(
+                    self.connection.atoms(),
+                    &net_wm_name,
+                    &wm_name.reply()?,
+                ))
+            });
+            let bounds = geometry.reply().and_then(|geometry| {
+                let rect = parse_rect(&geometry, &origin.reply()?);
+                let frame_extents = parse_frame_extents(&frame_extents.reply()?);
+
+                Ok(extend_by_frame(rect, frame_extents).into())
+            });
+            let owner_pid = pid
+                .reply()
+                .map_err(X11Error::from)
+                .and_then(|pid| self.resolve_pid(&pid));
+            let owner_name = match owner_pid {
+                Ok(pid) => client_machine
+                    .reply()
+                    .map_err(X11Error::from)
+                    .and_then(|client_machine| self.local_pid(pid, &client_machine))
+                    .map(|pid| pid.and_then(process_name)),
+                // The error cannot be cloned, so it is read again for the owner name.
+                Err(_) => self.owner_name(),
+            };
+
+            Ok(X11WindowProperties {
+                title: title.map_err(Into::into),
+                bounds: bounds.map_err(Into::into),
+                owner_pid: owner_pid.map(|(pid, _)| pid),
+                owner_name,
+            })
+        }
+
+        fn title_cookies(&self) -> Result<[PropertyCookie<'_>; 2], X11Error> {
+            let atoms = self.connection.atoms();
+
+            Ok([
+                self.connection
+                    .property_cookie(self.id, atoms._NET_WM_NAME, atoms.UTF8_STRING)?,
+                self.connection
+                    .property_cookie(self.id, AtomEnum::WM_NAME, AtomEnum::ANY)?,
+            ])
+        }
+
+        fn frame_extents_cookie(&self) -> Result<PropertyCookie<'_>, X11Error> {
+            self.connection.property_cookie(
+                self.id,
+                self.connection.atoms()._NET_FRAME_EXTENTS,
+                AtomEnum::CARDINAL,
+            )
+        }
+
+        /// Parses `_NET_WM_PID`, falling back to the X-Resource extension if it is not set.
+        fn resolve_pid(
+            &self,
+            net_wm_pid: &GetPropertyReply,
+        ) -> Result<(u32, X11PidSource), X11Error> {
+            match parse_pid(net_wm_pid) {
+                Ok(pid) => Ok((pid, X11PidSource::NetWmPid)),
+                Err(error) => match self.connection.res_client_pids(&[self.id])?.pop().flatten() {
+                    Some(pid) => Ok((pid, X11PidSource::XRes)),
+                    None => Err(error),
+                },
+            }
+        }
+
+        /// Returns the process ID if the owner runs on this machine.
+        /// See [`local_owner_pid`](Self::local_owner_pid).
+        fn local_pid(
+            &self,
+            (pid, source): (u32, X11PidSource),
+            client_machine: &GetPropertyReply,
+        ) -> Result<Option<u32>, X11Error> {
+            if source == X11PidSource::XRes || is_local_machine(client_machine) {
+                return Ok(Some(pid));
+            }
+
+            Ok(self.connection.res_client_pids(&[self.id])?.pop().flatten())
+        }
+
+        fn client_machine_cookie(&self) -> Result<PropertyCookie<'_>, X11Error> {
+            self.connection
+                .property_cookie(self.id, AtomEnum::WM_CLIENT_MACHINE, AtomEnum::STRING)
+        }
+
+        fn pid_cookie(&self) -> Result<PropertyCookie<'_>, X11Error> {
+            self.connection.property_cookie(
+                self.id,
+                self.connection.atoms()._NET_WM_PID,
+                AtomEnum::CARDINAL,
+            )
+        }
+    }
+
+    /// The way the process ID of a window's owner was resolved,
+    /// returned by [`X11Window::owner_pid_with_source`].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum X11PidSource {
+        /// The process ID was read from `_NET_WM_PID` set by the client.
+        NetWmPid,
+        /// The process ID was reported by the X server through `XResQueryClientIds`
+        /// of the X-Resource extension.
+        XRes,
+    }
+
+    /// The properties of a window read by [`X11Window::properties`].
+    #[derive(Debug)]
+    pub struct X11WindowProperties {
+        pub title: Result<Option<String>, X11Error>,
+        pub bounds: Result<Bounds, X11Error>,
+        pub owner_pid: Result<u32, X11Error>,
+        /// The name of the owner process. See [`X11Window::owner_name`].
+        pub owner_name: Result<Option<String>, X11Error>,
+    }
+
+    type PropertyCookie<'a> = Cookie<'a, RustConnection, GetPropertyReply>;
+
+    fn parse_title(
+        atoms: &Atoms,
+        net_wm_name: &GetPropertyReply,
+        wm_name: &GetPropertyReply,
+    ) -> Option<String> {
+        if net_wm_name.format == 8 && net_wm_name.type_ == atoms.UTF8_STRING {
+            return Some(String::from_utf8_lossy(&net_wm_name.value).into_owned());
+        }
+
+        if wm_name.format == 8 && wm_name.type_ != u32::from(AtomEnum::NONE) {
+            // `STRING` is encoded in ISO Latin-1, which maps directly to Unicode code points.
+            let title = if wm_name.type_ == atoms.UTF8_STRING {
+                String::from_utf8_lossy(&wm_name.value).into_owned()
+            } else {
+                wm_name.value.iter().map(|&byte| byte as char).collect()
+            };
+
+            return Some(title);
+        }
+
+        None
+    }
+
+    /// Parses `WM_TRANSIENT_FOR`. The root window is ignored because some clients set it
+    /// for the dialogs which belong to the whole application.
+    pub fn parse_transient_for(reply: &GetPropertyReply, root: X11WindowId) -> Option<X11WindowId> {
+        reply
+            .value32()?
+            .next()
+            .filter(|&window| window != x11rb::NONE && window != root)
+    }
+
+    /// Parses `_NET_FRAME_EXTENTS`, which is the widths of the left, right, top and bottom
+    /// borders added by the window manager.
+    pub fn parse_frame_extents(reply: &GetPropertyReply) -> Option<[u32; 4]> {
+        let mut values = reply.value32()?;
+
+        Some([
+            values.next()?,
+            values.next()?,
+            values.next()?,
+            values.next()?,
+        ])
+    }
+
+    fn parse_rect(geometry: &GetGeometryReply, origin: &TranslateCoordinatesReply) -> X11Bounds {
+        X11Bounds {
+            x: origin.dst_x,
+            y: origin.dst_y,
+            width: geometry.width,
+            height: geometry.height,
+        }
+    }
+
+    /// Extends the rectangle of a client window by its frame extents.
+    /// The rectangle is returned as is if the window manager does not set them.
+    pub fn extend_by_frame(mut rect: X11Bounds, frame_extents: Option<[u32; 4]>) -> X11Bounds {
+        if let Some([left, right, top, bottom]) = frame_extents {
+            rect.x = rect.x.saturating_sub(left as _);
+            rect.y = rect.y.saturating_sub(top as _);
+            rect.width = rect.width.saturating_add((left + right) as _);
+            rect.height = rect.height.saturating_add((top + bottom) as _);
+        }
+
+        rect
+    }
+
+    /// Parses `_NET_WM_PID`.
+    pub fn parse_pid(reply: &GetPropertyReply) -> Result<u32, X11Error> {
+        reply
+            .value32()
+            .and_then(|mut values| values.next())
+            .ok_or(X11Error::MissingProperty("_NET_WM_PID"))
+    }
+
+    /// Parses `WM_CLASS`, which is the instance name and the class terminated by null.
+    pub fn parse_wm_class(reply: &GetPropertyReply) -> Option<(String, String)> {
+        if reply.format != 8 {
+            return None;
+        }
+
+        // `STRING` is encoded in ISO Latin-1, which maps directly to Unicode code points.
+        let mut values = reply
+            .value
+            .split(|&byte| byte == 0)
+            .map(|value| value.iter().map(|&byte| byte as char).collect::<String>());
+
+        Some((values.next()?, values.next()?))
+    }
+
+    /// Parses `WM_CLIENT_MACHINE`, which is the name of the machine the client runs on.
+    pub fn parse_client_machine(reply: &GetPropertyReply) -> Option<String> {
+        if reply.format != 8 {
+            return None;
+        }
+
+        let name = reply.value.split(|&byte| byte == 0).next()?;
+        let name = name.iter().map(|&byte| byte as char).collect::<String>();
+
+        (!name.is_empty()).then_some(name)
+    }
+
+    /// Checks if the host names refer to the same machine.
+    /// A short name matches the fully qualified name which starts with it.
+    pub fn is_same_host(a: &str, b: &str) -> bool {
+        let (a, b) = (a.to_ascii_lowercase(), b.to_ascii_lowercase());
+        let is_short_name_of = |short: &str, long: &str| {
+            long.strip_prefix(short)
+                .is_some_and(|domain| domain.starts_with('.'))
+        };
+
+        a == b || is_short_name_of(&a, &b) || is_short_name_of(&b, &a)
+    }
+
+    /// Checks if `WM_CLIENT_MACHINE` is the name of this machine.
+    /// It is `false` if the property is not set.
+    fn is_local_machine(client_machine: &GetPropertyReply) -> bool {
+        let Some(client_machine) = parse_client_machine(client_machine) else {
+            return false;
+        };
+        let uname = rustix::system::uname();
+
+        is_same_host(&client_machine, &uname.nodename().to_string_lossy())
+    }
+
+    /// Reads the path of the executable file of the process from `/proc/<pid>/exe`.
+    fn process_executable(pid: u32) -> Option<PathBuf> {
+        std::fs::read_link(format!("/proc/{pid}/exe")).ok()
+    }
+
+    /// Reads the name of the process from `/proc/<pid>/comm`.
+    fn process_name(pid: u32) -> Option<String> {
+        std::fs::read_to_string(format!("/proc/{pid}/comm"))
+            .ok()
+            .map(|name| name.trim_end().to_owned())
+    }
+}
+
+mod notifier {
+    use std::{
+        collections::HashSet,
+        os::fd::{AsFd, BorrowedFd},
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    use x11rb::{
+        connection::Connection,
+        protocol::{
+            Event,
+            xproto::{AtomEnum, ChangeWindowAttributesAux, ConnectionExt as _, EventMask},
+        },
+    };
+
+    use super::{X11Connection, X11Error, X11WindowId};
+
+    /// Receives the events which are sent when the windows change.
+    ///
+    /// It listens to `SubstructureNotify` and `PropertyChange` on the root window,
+    /// which report the frames being mapped, moved or resized and the changes of
+    /// `_NET_CLIENT_LIST` and `_NET_ACTIVE_WINDOW`, and to `PropertyChange` on each client
+    /// window, which reports the changes of the titles and the frame extents.
+    /// The changes of the other properties are ignored.
+    pub(crate) struct X11Notifier {
+        connection: Arc<X11Connection>,
+        watched: HashSet<X11WindowId>,
+    }
+
+    impl X11Notifier {
+        pub fn new() -> Result<Self, X11Error> {
+            // The shared connection is not used because the events would pile up on it.
+            let connection = X11Connection::connect()?;
+
+            let aux = ChangeWindowAttributesAux::new()
+                .event_mask(EventMask::SUBSTRUCTURE_NOTIFY | EventMask::PROPERTY_CHANGE);
+            connection
+                .connection()
+                .change_window_attributes(connection.root(), &aux)?;
+            connection.connection().flush()?;
+
+            Ok(Self {
+                connection,
+                watched: HashSet::new(),
+            })
+        }
+
+        /// Starts listening to the client windows which are not listened to yet.
+        pub fn watch(&mut self, windows: &[X11WindowId]) -> Result<(), X11Error> {
+            let aux = ChangeWindowAttributesAux::new()
+                .event_mask(EventMask::PROPERTY_CHANGE | EventMask::STRUCTURE_NOTIFY);
+
+            for &window in windows {
+                if !self.watched.contains(&window) {
+                    // The error is ignored because the window may be destroyed already.
+                    self.connection
+                        .connection()
+                        .change_window_attributes(window, &aux)?
+                        .ignore_error();
+                }
+            }
+            self.connection.connection().flush()?;
+
+            self.watched = windows.iter().copied().collect();
+            Ok(())
+        }
+
+        /// Waits until a relevant event is received or the timeout elapses.
+        pub fn wait(&mut self, timeout: Duration) -> Result<(), X11Error> {
+            let deadline = Instant::now() + timeout;
+
+            while !self.drain()? {
+                let now = Instant::now();
+                if now >= deadline {
+                    break;
+                }
+
+                super::super::linux::wait_readable(self.as_fd(), deadline - now);
+            }
+
+            Ok(())
+        }
+
+        /// Discards the received events and returns `true` if any of them is relevant.
+        /// It does not block.
+        pub fn drain(&self) -> Result<bool, X11Error> {
+            let mut received = false;
+            while let Some(event) = self.connection.connection().poll_for_event()? {
+                received |= self.is_relevant(&event);
+            }
+
+            Ok(received)
+        }
+
+        /// Returns `true` if the event may change the windows.
+        ///
+        /// The property changes are only relevant for the window list, the active window,
+        /// the titles and the frame extents, which are included in the bounds.
+        fn is_relevant(&self, event: &Event) -> bool {
+            let atoms = self.connection.atoms();
+
+            match event {
+                Event::PropertyNotify(event) => [
+                    atoms._NET_CLIENT_LIST,
+                    atoms._NET_CLIENT_LIST_STACKING,
+                    atoms._NET_ACTIVE_WINDOW,
+                    atoms._NET_WM_NAME,
+                    atoms._NET_FRAME_EXTENTS,
+                    AtomEnum::WM_NAME.into(),
+                ]
+                .contains(&event.atom),
+                _ => true,
+            }
+        }
+    }
+
+    impl AsFd for X11Notifier {
+        fn as_fd(&self) -> BorrowedFd<'_> {
+            self.connection.connection().stream().as_fd()
+        }
+    }
+}
+
+pub mod error {
+    /// Low-level errors that can occur when interacting with the X server.
+    #[derive(Debug, thiserror::Error)]
+    pub enum X11Error {
+        /// Represents a failure to connect to the X server.
+        #[error("Failed to connect to the X server: {0}")]
+        Connect(#[from] x11rb::errors::ConnectError),
+        /// Represents a failure of the established connection.
+        #[error("The connection to the X server failed: {0}")]
+        Connection(#[from] x11rb::errors::ConnectionError),
+        /// Represents an error reply from the X server.
+        #[error("The X server returned an error: {0}")]
+        Reply(#[from] x11rb::errors::ReplyError),
+        /// Represents a situation when a required window property is not set.
+        /// For example, `_NET_CLIENT_LIST` is missing when no EWMH-compliant
+        /// window manager is running.
+        #[error("The window property `{0}` is not set.")]
+        MissingProperty(&'static str),
+    }
+
+    impl From<X11Error> for crate::Error {
+        fn from(error: X11Error) -> Self {
+            Self::PlatformSpecificError(crate::platform_impl::linux::LinuxError::X11(error))
+        }
+    }
+}
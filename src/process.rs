@@ -0,0 +1,42 @@
+use std::{path::PathBuf, time::SystemTime};
+
+/// The information of the process which owns a window, returned by
+/// [`Window::owner_process`](crate::Window::owner_process).
+///
+/// Each field except [`pid`](Self::pid) is `None` if it is not provided by the platform
+/// or could not be read, e.g. because the process belongs to another user.
+/// See [`Window::owner_process`](crate::Window::owner_process) for the fields provided
+/// on each platform.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcessInfo {
+    pub pid: i32,
+    /// The name of the process. It is the same as [`Window::owner_name`](crate::Window::owner_name).
+    pub name: Option<String>,
+    /// The path of the executable file.
+    pub executable: Option<PathBuf>,
+    /// The command line arguments including the program name.
+    pub command_line: Option<Vec<String>>,
+    pub parent_pid: Option<i32>,
+    /// The real user ID of the process.
+    pub user_id: Option<u32>,
+    /// The time when the process was started.
+    pub start_time: Option<SystemTime>,
+}
+
+impl ProcessInfo {
+    /// Creates a new [`ProcessInfo`] of the process without any information.
+    ///
+    /// It is useful to implement [`BackendWindow::owner_process`](crate::BackendWindow::owner_process).
+    pub fn new(pid: i32) -> Self {
+        Self {
+            pid,
+            name: None,
+            executable: None,
+            command_line: None,
+            parent_pid: None,
+            user_id: None,
+            start_time: None,
+        }
+    }
+}
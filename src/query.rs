@@ -0,0 +1,265 @@
+use crate::{Error, Window, WindowBackend};
+
+/// A set of conditions to find windows.
+///
+/// A window matches the query when it meets all the conditions. If a property required by
+/// a condition cannot be read, the window does not match, except for
+/// [`exclude_own_process`](Self::exclude_own_process) which only excludes the windows known
+/// to be owned by the current process.
+///
+/// The backends may check the conditions in a cheaper way than reading the properties
+/// of every window. For example, the X11 backend requests the process IDs of all the windows
+/// at once before reading the other properties.
+///
+/// # Examples
+/// ```no_run
+/// use window_getter::WindowQuery;
+///
+/// let windows = WindowQuery::new()
+///     .owner_name("firefox")
+///     .min_size(100.0, 100.0)
+///     .on_screen_only()
+///     .find_all()?;
+///
+/// for window in windows {
+///     println!("{:?}", window.title());
+/// }
+/// # Ok::<(), window_getter::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct WindowQuery {
+    title_contains: Option<String>,
+    #[cfg(feature = "regex")]
+    title_regex: Option<regex::Regex>,
+    owner_name: Option<String>,
+    owner_pid: Option<i32>,
+    min_size: Option<(f64, f64)>,
+    on_screen_only: bool,
+    applications_only: bool,
+    exclude_own_process: bool,
+    limit: Option<usize>,
+}
+
+impl WindowQuery {
+    /// Creates a new query which matches all windows.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches the windows whose title contains the text. It is case-sensitive.
+    pub fn title_contains(mut self, text: impl Into<String>) -> Self {
+        self.title_contains = Some(text.into());
+        self
+    }
+
+    /// Matches the windows whose title matches the regular expression.
+    #[cfg(feature = "regex")]
+    pub fn title_regex(mut self, regex: regex::Regex) -> Self {
+        self.title_regex = Some(regex);
+        self
+    }
+
+    /// Matches the windows whose owner has the name.
+    /// See [`Window::owner_name`] for what the name is on each platform.
+    pub fn owner_name(mut self, name: impl Into<String>) -> Self {
+        self.owner_name = Some(name.into());
+        self
+    }
+
+    /// Matches the windows owned by the process.
+    pub fn owner_pid(mut self, pid: i32) -> Self {
+        self.owner_pid = Some(pid);
+        self
+    }
+
+    /// Matches the windows whose width and height are at least the given values.
+    pub fn min_size(mut self, width: f64, height: f64) -> Self {
+        self.min_size = Some((width, height));
+        self
+    }
+
+    /// Matches only the windows on the screen. See [`Window::is_on_screen`].
+    pub fn on_screen_only(mut self) -> Self {
+        self.on_screen_only = true;
+        self
+    }
+
+    /// Matches only the windows of applications, i.e. the normal windows and the dialogs,
+    /// excluding the docks, the tooltips, the desktop and so on.
+    /// See [`Window::kind`] and [`WindowKind::is_application`](crate::WindowKind::is_application).
+    ///
+    /// The windows whose kind cannot be read do not match.
+    pub fn applications_only(mut self) -> Self {
+        self.applications_only = true;
+        self
+    }
+
+    /// Excludes the windows owned by the current process.
+    pub fn exclude_own_process(mut self) -> Self {
+        self.exclude_own_process = true;
+        self
+    }
+
+    /// Limits the number of windows to find.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Finds all the matching windows in front-to-back order.
+    ///
+    /// Like [`get_windows`](crate::get_windows), it uses the `MockDesktop` of the `mock` feature
+    /// installed on the current thread if any.
+    pub fn find_all(&self) -> Result<Vec<Window>, Error> {
+        crate::with_default_backend(|backend| self.find_all_in(backend))
+    }
+
+    /// Finds the frontmost matching window.
+    pub fn find_first(&self) -> Result<Option<Window>, Error> {
+        crate::with_default_backend(|backend| self.find_first_in(backend))
+    }
+
+    /// Finds all the matching windows in the backend.
+    pub fn find_all_in(
+        &self,
+        backend: &(impl WindowBackend + ?Sized),
+    ) -> Result<Vec<Window>, Error> {
+        backend.find_windows(self)
+    }
+
+    /// Finds the frontmost matching window in the backend.
+    pub fn find_first_in(
+        &self,
+        backend: &(impl WindowBackend + ?Sized),
+    ) -> Result<Option<Window>, Error> {
+        let query = self.clone().limit(1);
+
+        Ok(backend.find_windows(&query)?.into_iter().next())
+    }
+
+    /// Finds the frontmost matching window which contains the point.
+    ///
+    /// It is useful to skip the windows of the current process, e.g. an overlay drawn
+    /// over the point, by [`exclude_own_process`](Self::exclude_own_process).
+    /// See [`get_window_at`](crate::get_window_at) for the details.
+    pub fn find_at(&self, x: f64, y: f64) -> Result<Option<Window>, Error> {
+        crate::with_default_backend(|backend| self.find_at_in(backend, x, y))
+    }
+
+    /// Finds the frontmost matching window which contains the point in the backend.
+    pub fn find_at_in(
+        &self,
+        backend: &(impl WindowBackend + ?Sized),
+        x: f64,
+        y: f64,
+    ) -> Result<Option<Window>, Error> {
+        backend.find_window_at(self, x, y)
+    }
+
+    /// Checks if the window matches the query.
+    /// The [`limit`](Self::limit) is not taken into account.
+    pub fn matches(&self, window: &Window) -> bool {
+        (!self.has_owner_pid_condition() || self.matches_owner_pid(window.owner_pid().ok()))
+            && self.matches_except_owner_pid(window)
+    }
+
+    /// Returns `true` if the query has a condition on the owner's process ID.
+    pub(crate) fn has_owner_pid_condition(&self) -> bool {
+        self.owner_pid.is_some() || self.exclude_own_process
+    }
+
+    /// Checks the conditions on the owner's process ID.
+    /// `pid` is `None` if it could not be read.
+    pub(crate) fn matches_owner_pid(&self, pid: Option<i32>) -> bool {
+        if self.owner_pid.is_some() && pid != self.owner_pid {
+            return false;
+        }
+
+        !(self.exclude_own_process && pid == Some(std::process::id() as i32))
+    }
+
+    /// Checks all the conditions except the ones on the owner's process ID.
+    pub(crate) fn matches_except_owner_pid(&self, window: &Window) -> bool {
+        if self.has_title_condition() {
+            let Ok(Some(title)) = window.title() else {
+                return false;
+            };
+
+            if !self.matches_title(&title) {
+                return false;
+            }
+        }
+
+        if let Some(name) = &self.owner_name
+            && window.owner_name().ok().flatten().as_ref() != Some(name)
+        {
+            return false;
+        }
+
+        if let Some((width, height)) = self.min_size
+            && !window
+                .bounds()
+                .is_ok_and(|bounds| bounds.width >= width && bounds.height >= height)
+        {
+            return false;
+        }
+
+        if self.applications_only && !window.kind().is_ok_and(|kind| kind.is_application()) {
+            return false;
+        }
+
+        !self.on_screen_only || window.is_on_screen().unwrap_or(false)
+    }
+
+    /// Takes the windows which pass `matches` up to the [`limit`](Self::limit).
+    pub(crate) fn take_matches(
+        &self,
+        windows: impl IntoIterator<Item = Window>,
+        matches: impl Fn(&Window) -> bool,
+    ) -> Vec<Window> {
+        windows
+            .into_iter()
+            .filter(|window| matches(window))
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+
+    /// Takes the first window which contains the point and matches the query.
+    /// The windows known to be off the screen are skipped.
+    pub(crate) fn take_match_at(
+        &self,
+        windows: impl IntoIterator<Item = Window>,
+        x: f64,
+        y: f64,
+    ) -> Option<Window> {
+        windows.into_iter().find(|window| {
+            window.is_on_screen().unwrap_or(true)
+                && window
+                    .bounds()
+                    .is_ok_and(|bounds| bounds.contains_point(x, y))
+                && self.matches(window)
+        })
+    }
+
+    fn has_title_condition(&self) -> bool {
+        #[cfg(feature = "regex")]
+        if self.title_regex.is_some() {
+            return true;
+        }
+
+        self.title_contains.is_some()
+    }
+
+    fn matches_title(&self, title: &str) -> bool {
+        #[cfg(feature = "regex")]
+        if let Some(regex) = &self.title_regex
+            && !regex.is_match(title)
+        {
+            return false;
+        }
+
+        self.title_contains
+            .as_ref()
+            .is_none_or(|text| title.contains(text.as_str()))
+    }
+}
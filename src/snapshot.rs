@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use crate::{Bounds, Error, Window, WindowId};
+
+/// An owned copy of the properties of a [`Window`].
+///
+/// Unlike [`Window`], it does not refer to the live window, so it can be stored,
+/// compared later or sent to other processes with the `serde` feature.
+/// You can get it by [`Window::snapshot`] or [`get_window_snapshots`](crate::get_window_snapshots).
+///
+/// Each property is `None` if it could not be read, and the error is stored in
+/// [`errors`](Self::errors).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowSnapshot {
+    pub id: WindowId,
+    pub title: Option<String>,
+    pub bounds: Option<Bounds>,
+    pub owner_pid: Option<i32>,
+    pub owner_name: Option<String>,
+    /// The errors that occurred while reading the properties.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "SnapshotErrors::is_empty")
+    )]
+    pub errors: SnapshotErrors,
+}
+
+/// The errors that occurred while reading each property of a [`WindowSnapshot`].
+///
+/// The errors are shared by [`Arc`] so that the snapshot can be cloned.
+///
+/// With the `serde` feature, it is serialized as the messages of the errors keyed by the names
+/// of the properties, and they are deserialized as [`Error::Recorded`]. So the properties which
+/// could not be read are still distinguished from the ones which were read as `None`
+/// in a snapshot restored from the disk, e.g. by [`diff_snapshots`](crate::diff_snapshots).
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "RecordedErrors", into = "RecordedErrors")
+)]
+pub struct SnapshotErrors {
+    pub title: Option<Arc<Error>>,
+    pub bounds: Option<Arc<Error>>,
+    pub owner_pid: Option<Arc<Error>>,
+    pub owner_name: Option<Arc<Error>>,
+}
+
+impl SnapshotErrors {
+    /// Returns `true` if all the properties were read successfully.
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Iterates over the errors with the names of the properties.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &Error)> {
+        [
+            ("title", &self.title),
+            ("bounds", &self.bounds),
+            ("owner_pid", &self.owner_pid),
+            ("owner_name", &self.owner_name),
+        ]
+        .into_iter()
+        .filter_map(|(name, error)| Some((name, error.as_deref()?)))
+    }
+}
+
+/// The serialized form of [`SnapshotErrors`].
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RecordedErrors {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bounds: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    owner_pid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    owner_name: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+impl From<SnapshotErrors> for RecordedErrors {
+    fn from(errors: SnapshotErrors) -> Self {
+        let message = |error: Option<Arc<Error>>| error.map(|error| error.to_string());
+
+        Self {
+            title: message(errors.title),
+            bounds: message(errors.bounds),
+            owner_pid: message(errors.owner_pid),
+            owner_name: message(errors.owner_name),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<RecordedErrors> for SnapshotErrors {
+    fn from(errors: RecordedErrors) -> Self {
+        let error =
+            |message: Option<String>| message.map(|message| Arc::new(Error::Recorded(message)));
+
+        Self {
+            title: error(errors.title),
+            bounds: error(errors.bounds),
+            owner_pid: error(errors.owner_pid),
+            owner_name: error(errors.owner_name),
+        }
+    }
+}
+
+impl WindowSnapshot {
+    /// Creates a snapshot from the results of reading each property.
+    ///
+    /// It is useful to implement [`BackendWindow::snapshot`](crate::BackendWindow::snapshot).
+    pub fn from_results(
+        id: WindowId,
+        title: Result<Option<String>, Error>,
+        bounds: Result<Bounds, Error>,
+        owner_pid: Result<i32, Error>,
+        owner_name: Result<Option<String>, Error>,
+    ) -> Self {
+        let mut errors = SnapshotErrors::default();
+
+        Self {
+            id,
+            title: take_error(title, &mut errors.title).flatten(),
+            bounds: take_error(bounds, &mut errors.bounds),
+            owner_pid: take_error(owner_pid, &mut errors.owner_pid),
+            owner_name: take_error(owner_name, &mut errors.owner_name).flatten(),
+            errors,
+        }
+    }
+
+    /// Creates a snapshot in which no property could be read because of the error.
+    pub fn from_error(id: WindowId, error: Error) -> Self {
+        let error = Some(Arc::new(error));
+
+        Self {
+            id,
+            title: None,
+            bounds: None,
+            owner_pid: None,
+            owner_name: None,
+            errors: SnapshotErrors {
+                title: error.clone(),
+                bounds: error.clone(),
+                owner_pid: error.clone(),
+                owner_name: error,
+            },
+        }
+    }
+}
+
+impl From<&Window> for WindowSnapshot {
+    fn from(window: &Window) -> Self {
+        window.snapshot()
+    }
+}
+
+fn take_error<T>(result: Result<T, Error>, error: &mut Option<Arc<Error>>) -> Option<T> {
+    result.map_err(|e| *error = Some(Arc::new(e))).ok()
+}
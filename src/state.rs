@@ -0,0 +1,21 @@
+bitflags::bitflags! {
+    /// The state of a window returned by [`Window::state`](crate::Window::state).
+    ///
+    /// Not every platform can tell every flag. See [`Window::state`](crate::Window::state)
+    /// for the flags reported on each platform.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct WindowState: u32 {
+        /// The window is minimized, i.e. iconified.
+        const MINIMIZED = 1 << 0;
+        /// The window is maximized.
+        const MAXIMIZED = 1 << 1;
+        /// The window is fullscreen.
+        const FULLSCREEN = 1 << 2;
+        /// The window is hidden by the window manager or the application,
+        /// e.g. it is minimized or shaded.
+        const HIDDEN = 1 << 3;
+        /// The window is on the screen. See [`Window::is_on_screen`](crate::Window::is_on_screen).
+        const ON_SCREEN = 1 << 4;
+    }
+}
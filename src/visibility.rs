@@ -0,0 +1,100 @@
+use crate::{Bounds, Window, WindowId};
+
+/// The part of a window which is not covered by the windows above it,
+/// computed by [`compute_visibility`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowVisibility {
+    pub id: WindowId,
+    /// The bounds of the window. It is `None` if they could not be read.
+    pub bounds: Option<Bounds>,
+    /// The visible part of the window as non-overlapping rectangles.
+    /// It is empty if the window is fully covered, off the screen or its bounds are unknown.
+    pub visible_region: Vec<Bounds>,
+    /// The ratio of the visible area to the area of the window, from `0.0` to `1.0`.
+    /// It is `None` if the bounds could not be read, and `0.0` if the window has no area.
+    pub visible_fraction: Option<f64>,
+}
+
+impl WindowVisibility {
+    /// Returns the total area of the [visible region](Self::visible_region).
+    pub fn visible_area(&self) -> f64 {
+        self.visible_region.iter().map(Bounds::area).sum()
+    }
+
+    /// Returns `true` if no part of the window is known to be visible.
+    pub fn is_hidden(&self) -> bool {
+        self.visible_region.is_empty()
+    }
+}
+
+/// Computes which part of each window is visible, given the windows in front-to-back order
+/// like the result of [`get_windows`](crate::get_windows).
+///
+/// Each window is covered by the [bounds](Window::bounds) of the windows before it in the list.
+/// The windows which are not [on the screen](Window::is_on_screen) or whose bounds cannot be
+/// read do not cover the others. The results are in the same order as `windows`.
+///
+/// It only compares the rectangular bounds, so the transparent or non-rectangular
+/// parts of the windows are treated as opaque.
+///
+/// # Examples
+/// ```no_run
+/// let windows = window_getter::get_windows()?;
+///
+/// for visibility in window_getter::compute_visibility(&windows) {
+///     if !visibility.is_hidden() {
+///         println!("{:?} is {:?} visible", visibility.id, visibility.visible_fraction);
+///     }
+/// }
+/// # Ok::<(), window_getter::Error>(())
+/// ```
+pub fn compute_visibility(windows: &[Window]) -> Vec<WindowVisibility> {
+    let mut occluders: Vec<Bounds> = Vec::new();
+
+    windows
+        .iter()
+        .map(|window| {
+            let bounds = window.bounds().ok();
+            let on_screen = window.is_on_screen().unwrap_or(true);
+
+            let visible_region = match &bounds {
+                Some(bounds) if on_screen => {
+                    occluders
+                        .iter()
+                        .fold(vec![bounds.clone()], |region, occluder| {
+                            region
+                                .iter()
+                                .flat_map(|part| part.subtract(occluder))
+                                .collect()
+                        })
+                }
+                _ => Vec::new(),
+            };
+
+            let visible_area = visible_region.iter().map(Bounds::area).sum::<f64>();
+            let visible_fraction = bounds.as_ref().map(|bounds| {
+                if bounds.is_empty() {
+                    0.0
+                } else {
+                    visible_area / bounds.area()
+                }
+            });
+            let visibility = WindowVisibility {
+                id: window.id(),
+                bounds,
+                visible_region,
+                visible_fraction,
+            };
+
+            if let Some(bounds) = &visibility.bounds
+                && on_screen
+                && !bounds.is_empty()
+            {
+                occluders.push(bounds.clone());
+            }
+
+            visibility
+        })
+        .collect()
+}
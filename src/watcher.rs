@@ -0,0 +1,353 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    Bounds, Change, Error, Window, WindowBackend, WindowChange, WindowId, WindowSnapshot,
+    diff_snapshots,
+};
+
+/// A change of a window reported by [`WindowWatcher`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum WindowEvent {
+    /// The window was opened.
+    Created(WindowId),
+    /// The window was closed.
+    Destroyed(WindowId),
+    /// The window was moved to the new bounds.
+    Moved { id: WindowId, bounds: Bounds },
+    /// The window was resized to the new bounds.
+    Resized { id: WindowId, bounds: Bounds },
+    /// The title of the window was changed.
+    TitleChanged { id: WindowId, title: Option<String> },
+    /// The window gained or lost the keyboard focus.
+    FocusChanged { id: WindowId, focused: bool },
+}
+
+impl WindowEvent {
+    /// Returns the identifier of the window the event is about.
+    pub fn id(&self) -> WindowId {
+        match self {
+            Self::Created(id) | Self::Destroyed(id) => *id,
+            Self::Moved { id, .. }
+            | Self::Resized { id, .. }
+            | Self::TitleChanged { id, .. }
+            | Self::FocusChanged { id, .. } => *id,
+        }
+    }
+}
+
+/// Watches the windows and reports their changes as [`WindowEvent`]s.
+///
+/// It compares the windows with the ones of the last check by [`diff_snapshots`] and
+/// emits the events for the differences. The windows existing when the watcher is created are not reported
+/// as [`WindowEvent::Created`].
+///
+/// The properties which cannot be read, e.g. the bounds on Wayland, are not compared.
+///
+/// # Platform-specific
+/// - **Linux (X11)**: The windows are checked when the X server sends `SubstructureNotify`
+///   events on the root window, or `PropertyNotify` events of `_NET_CLIENT_LIST`,
+///   `_NET_CLIENT_LIST_STACKING`, `_NET_ACTIVE_WINDOW`, the titles and `_NET_FRAME_EXTENTS`.
+/// - **Linux (Wayland)**: The windows are checked when the compositor sends
+///   toplevel events.
+/// - **macOS** / **Windows**: The windows are checked at the
+///   [poll interval](Self::poll_interval).
+///
+/// On Linux, the windows are also checked at the poll interval in case a notification
+/// is missed.
+///
+/// # Examples
+/// ```no_run
+/// use window_getter::{WindowEvent, WindowWatcher};
+///
+/// for event in WindowWatcher::new()? {
+///     match event? {
+///         WindowEvent::Created(id) => println!("{id:?} was opened"),
+///         WindowEvent::TitleChanged { id, title } => println!("{id:?} is now {title:?}"),
+///         _ => {}
+///     }
+/// }
+/// # Ok::<(), window_getter::Error>(())
+/// ```
+pub struct WindowWatcher {
+    backend: Box<dyn WindowBackend + Send>,
+    wakeup: Wakeup,
+    snapshots: Vec<WindowSnapshot>,
+    focused: HashMap<WindowId, bool>,
+    events: VecDeque<WindowEvent>,
+    poll_interval: Duration,
+}
+
+impl WindowWatcher {
+    /// The default value of [`poll_interval`](Self::poll_interval).
+    pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    /// Creates a watcher of the windows of the running system.
+    ///
+    /// If a `MockDesktop` of the `mock` feature is installed on the current thread,
+    /// it watches the desktop by polling instead.
+    pub fn new() -> Result<Self, Error> {
+        #[cfg(feature = "mock")]
+        if let Some(desktop) = crate::mock::installed() {
+            return Self::with_backend(desktop);
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            use crate::platform_impl::linux::{LinuxNotifier, detect_backend};
+
+            let backend = detect_backend()?;
+            let notifier = LinuxNotifier::new(backend)?;
+
+            Self::build(Box::new(backend), Wakeup::Native(notifier))
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            Self::with_backend(crate::PlatformBackend)
+        }
+    }
+
+    /// Creates a watcher of the windows of the backend.
+    /// The windows are checked at the [poll interval](Self::poll_interval).
+    pub fn with_backend(backend: impl WindowBackend + Send + 'static) -> Result<Self, Error> {
+        Self::build(Box::new(backend), Wakeup::Poll)
+    }
+
+    fn build(backend: Box<dyn WindowBackend + Send>, wakeup: Wakeup) -> Result<Self, Error> {
+        let mut watcher = Self {
+            backend,
+            wakeup,
+            snapshots: Vec::new(),
+            focused: HashMap::new(),
+            events: VecDeque::new(),
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+        };
+        (watcher.snapshots, watcher.focused) = watcher.read_windows()?;
+
+        Ok(watcher)
+    }
+
+    /// Sets the interval to check the windows.
+    /// It is [`DEFAULT_POLL_INTERVAL`](Self::DEFAULT_POLL_INTERVAL) by default.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Checks the windows now and returns the events which have not been returned yet,
+    /// without waiting.
+    pub fn poll_events(&mut self) -> Result<Vec<WindowEvent>, Error> {
+        // Discards the notifications received so far, which are covered by this check.
+        self.wakeup.wait(Duration::ZERO)?;
+        self.refresh()?;
+
+        Ok(self.events.drain(..).collect())
+    }
+
+    /// Waits for the next event.
+    pub fn next_event(&mut self) -> Result<WindowEvent, Error> {
+        loop {
+            if let Some(event) = self.next_event_timeout(self.poll_interval)? {
+                return Ok(event);
+            }
+        }
+    }
+
+    /// Waits for the next event until the timeout elapses.
+    /// It returns `None` if no event occurred in time.
+    pub fn next_event_timeout(&mut self, timeout: Duration) -> Result<Option<WindowEvent>, Error> {
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Ok(Some(event));
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(None);
+            }
+
+            self.wakeup.wait(self.poll_interval.min(deadline - now))?;
+            self.refresh()?;
+        }
+    }
+
+    /// Converts the watcher into a [`Stream`](futures_core::Stream) of the events,
+    /// which waits for the events without blocking the Tokio runtime.
+    ///
+    /// # Panics
+    /// It panics when it is not called in a Tokio runtime with the time driver enabled.
+    #[cfg(feature = "tokio")]
+    pub fn into_stream(self) -> crate::WindowEventStream {
+        crate::WindowEventStream::new(self)
+    }
+
+    /// Returns the file descriptor which becomes readable when a notification is received.
+    #[cfg(all(feature = "tokio", target_os = "linux"))]
+    pub(crate) fn notification_fd(&self) -> Option<std::os::fd::BorrowedFd<'_>> {
+        use std::os::fd::AsFd;
+
+        match &self.wakeup {
+            Wakeup::Poll => None,
+            Wakeup::Native(notifier) => Some(notifier.as_fd()),
+        }
+    }
+
+    /// Reads the notifications received so far without blocking and returns `true`
+    /// if the windows should be checked.
+    #[cfg(all(feature = "tokio", target_os = "linux"))]
+    pub(crate) fn take_notifications(&mut self) -> Result<bool, Error> {
+        match &mut self.wakeup {
+            Wakeup::Poll => Ok(false),
+            Wakeup::Native(notifier) => notifier.take_notifications(),
+        }
+    }
+
+    /// Returns the interval to check the windows.
+    #[cfg(feature = "tokio")]
+    pub(crate) fn interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    fn refresh(&mut self) -> Result<(), Error> {
+        let (snapshots, focused) = self.read_windows()?;
+        let diff = diff_snapshots(&self.snapshots, &snapshots);
+
+        self.events.extend(
+            diff.removed
+                .iter()
+                .map(|snapshot| WindowEvent::Destroyed(snapshot.id)),
+        );
+        self.events.extend(
+            diff.added
+                .iter()
+                .map(|snapshot| WindowEvent::Created(snapshot.id)),
+        );
+        for change in diff.changed {
+            push_change_events(change, &mut self.events);
+        }
+        for snapshot in &snapshots {
+            let id = snapshot.id;
+            if let (Some(old), Some(&focused)) = (self.focused.get(&id), focused.get(&id))
+                && *old != focused
+            {
+                self.events
+                    .push_back(WindowEvent::FocusChanged { id, focused });
+            }
+        }
+
+        self.snapshots = snapshots;
+        self.focused = focused;
+
+        Ok(())
+    }
+
+    /// Reads the snapshots and the focus of the windows.
+    ///
+    /// The foreground window is read once and compared with each window, since reading
+    /// the focus of every window may retrieve the whole window list on some platforms.
+    /// The map is empty if the foreground window could not be read.
+    fn read_windows(&mut self) -> Result<(Vec<WindowSnapshot>, HashMap<WindowId, bool>), Error> {
+        let windows = self.backend.get_windows()?;
+
+        let snapshots = windows.iter().map(Window::snapshot).collect::<Vec<_>>();
+        let focused = match self.backend.get_foreground_window() {
+            Ok(foreground) => {
+                let foreground = foreground.map(|window| window.id());
+                windows
+                    .iter()
+                    .map(|window| (window.id(), Some(window.id()) == foreground))
+                    .collect()
+            }
+            Err(_) => HashMap::new(),
+        };
+
+        let ids = snapshots
+            .iter()
+            .map(|snapshot| snapshot.id)
+            .collect::<Vec<_>>();
+        self.wakeup.watch(&ids)?;
+
+        Ok((snapshots, focused))
+    }
+}
+
+impl Debug for WindowWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WindowWatcher")
+            .field("snapshots", &self.snapshots)
+            .field("focused", &self.focused)
+            .field("events", &self.events)
+            .field("poll_interval", &self.poll_interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Iterator for WindowWatcher {
+    type Item = Result<WindowEvent, Error>;
+
+    /// Waits for the next event. It never returns `None`.
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_event())
+    }
+}
+
+/// How the watcher waits for the windows to change.
+enum Wakeup {
+    Poll,
+    #[cfg(target_os = "linux")]
+    Native(crate::platform_impl::linux::LinuxNotifier),
+}
+
+impl Wakeup {
+    fn watch(&mut self, _windows: &[WindowId]) -> Result<(), Error> {
+        match self {
+            Self::Poll => Ok(()),
+            #[cfg(target_os = "linux")]
+            Self::Native(notifier) => notifier.watch(_windows),
+        }
+    }
+
+    fn wait(&mut self, timeout: Duration) -> Result<(), Error> {
+        match self {
+            Self::Poll => {
+                std::thread::sleep(timeout);
+                Ok(())
+            }
+            #[cfg(target_os = "linux")]
+            Self::Native(notifier) => notifier.wait(timeout),
+        }
+    }
+}
+
+/// Pushes the events for the change of the title or the bounds of a window.
+fn push_change_events(change: WindowChange, events: &mut VecDeque<WindowEvent>) {
+    let id = change.id;
+
+    if let Some(Change {
+        old: Some(old),
+        new: Some(bounds),
+    }) = change.bounds
+    {
+        if (old.x, old.y) != (bounds.x, bounds.y) {
+            events.push_back(WindowEvent::Moved {
+                id,
+                bounds: bounds.clone(),
+            });
+        }
+        if (old.width, old.height) != (bounds.width, bounds.height) {
+            events.push_back(WindowEvent::Resized { id, bounds });
+        }
+    }
+
+    if let Some(Change { new: title, .. }) = change.title {
+        events.push_back(WindowEvent::TitleChanged { id, title });
+    }
+}
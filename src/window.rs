@@ -1,9 +1,21 @@
-use crate::{Bounds, Error, WindowId, platform_impl::PlatformWindow};
+use std::sync::Arc;
 
-/// A wrapper around a platform-specific window.
+use crate::{
+    AppIdentity, Bounds, CoordinateSpace, Error, Monitor, ProcessInfo, WindowId, WindowKind,
+    WindowSnapshot, WindowState, backend::BackendWindow, platform_impl::PlatformWindow,
+};
+
+/// A wrapper around a platform-specific window or a window provided by a custom
+/// [`WindowBackend`](crate::WindowBackend).
 /// This struct provides a cross-platform interface to interact with window properties.
 #[derive(Clone, Debug)]
-pub struct Window(pub(crate) PlatformWindow);
+pub struct Window(pub(crate) WindowInner);
+
+#[derive(Clone, Debug)]
+pub(crate) enum WindowInner {
+    Platform(PlatformWindow),
+    Backend(Arc<dyn BackendWindow>),
+}
 
 impl Window {
     /// Creates a new [`Window`] instance from a platform-specific window.
@@ -13,29 +25,44 @@ impl Window {
     /// or [`get_windows`](crate::get_windows) function so you don't need to create it manually
     /// in most use cases.
     pub fn new(inner: PlatformWindow) -> Self {
-        Self(inner)
+        Self(WindowInner::Platform(inner))
+    }
+
+    /// Creates a new [`Window`] instance from a window of a custom backend.
+    pub fn from_backend(window: impl BackendWindow) -> Self {
+        Self(WindowInner::Backend(Arc::new(window)))
     }
 
     /// Retrieves the underlying platform-specific window.
-    pub fn platform_window(&self) -> &PlatformWindow {
-        &self.0
+    /// It returns `None` if the window is provided by a custom backend.
+    pub fn platform_window(&self) -> Option<&PlatformWindow> {
+        match &self.0 {
+            WindowInner::Platform(window) => Some(window),
+            WindowInner::Backend(_) => None,
+        }
     }
 
     /// Consumes the `Window` and returns the underlying platform-specific window.
-    pub fn into_platform_window(self) -> PlatformWindow {
-        self.0
+    /// It returns `None` if the window is provided by a custom backend.
+    pub fn into_platform_window(self) -> Option<PlatformWindow> {
+        match self.0 {
+            WindowInner::Platform(window) => Some(window),
+            WindowInner::Backend(_) => None,
+        }
+    }
+
+    /// Retrieves the underlying window as a [`BackendWindow`].
+    /// The platform-specific windows also implement [`BackendWindow`].
+    pub fn backend_window(&self) -> &dyn BackendWindow {
+        match &self.0 {
+            WindowInner::Platform(window) => window,
+            WindowInner::Backend(window) => window.as_ref(),
+        }
     }
 
     /// Returns the unique identifier of the window.
     pub fn id(&self) -> WindowId {
-        #[cfg(target_os = "macos")]
-        {
-            WindowId(self.0.id())
-        }
-        #[cfg(target_os = "windows")]
-        {
-            WindowId(self.0.hwnd())
-        }
+        self.backend_window().id()
     }
 
     /// Returns the title of the window.
@@ -46,43 +73,47 @@ impl Window {
     /// - **macOS**: It will always return [`Ok`]. Apple's documentation does not
     ///   explicitly state this, but it returns `None` when the permission is not granted.
     pub fn title(&self) -> Result<Option<String>, Error> {
-        #[cfg(target_os = "macos")]
-        {
-            Ok(self.0.title())
-        }
-
-        #[cfg(target_os = "windows")]
-        {
-            Ok(self.0.title()?)
-        }
+        self.backend_window().title()
     }
 
-    /// Returns the bounds of the window.
+    /// Returns the bounds of the window in the native coordinate space of the platform.
+    /// Use [`bounds_in`](Self::bounds_in) to get them in a specific space.
+    ///
+    /// # Platform-specific
+    /// - **Windows**: They are in physical pixels.
+    /// - **macOS**: They are in logical points.
+    /// - **Linux (X11)**: They are in physical pixels relative to the root window.
+    /// - **Linux (Wayland)**: It will always return [`Error::Unsupported`](crate::Error::Unsupported)
+    ///   because Wayland does not expose the position of windows.
     pub fn bounds(&self) -> Result<Bounds, Error> {
-        #[cfg(target_os = "macos")]
-        {
-            Ok(self.0.bounds()?)
-        }
-        #[cfg(target_os = "windows")]
-        {
-            Ok(self.0.visible_bounds()?)
-        }
+        self.backend_window().bounds()
+    }
+
+    /// Returns the bounds of the window in the coordinate space.
+    ///
+    /// The bounds are converted by the [monitor](Self::monitor) of the window with
+    /// [`Monitor::convert`], so the windows on the monitors with different scale factors
+    /// can be compared. They are not converted if the window is on no monitor.
+    pub fn bounds_in(&self, space: CoordinateSpace) -> Result<Bounds, Error> {
+        let bounds = self.bounds()?;
+
+        Ok(match self.monitor()? {
+            Some(monitor) => monitor.convert(&bounds, space),
+            None => bounds,
+        })
     }
 
     /// Returns the process ID of the window's owner.
     ///
     /// # Platform-specific
     /// - **macOS**: It will always return [`Ok`].
+    /// - **Linux (X11)**: It is read from `_NET_WM_PID`. If the client does not set
+    ///   the property, it is asked to the X server by the X-Resource extension, and it will
+    ///   return an error if neither is available, e.g. the client is on another machine.
+    ///   See `X11Window::owner_pid_with_source`.
+    /// - **Linux (Wayland)**: It will always return [`Error::Unsupported`](crate::Error::Unsupported).
     pub fn owner_pid(&self) -> Result<i32, Error> {
-        #[cfg(target_os = "macos")]
-        {
-            Ok(self.0.owner_pid())
-        }
-
-        #[cfg(target_os = "windows")]
-        {
-            Ok(self.0.owner_pid()? as _)
-        }
+        self.backend_window().owner_pid()
     }
 
     /// Returns the name of the process that owns the window.
@@ -92,15 +123,209 @@ impl Window {
     ///   it will return [`Error::PermissionDenied`](crate::Error::PermissionDenied).
     ///   Also, it will return the name of the executable file when owner name is available.
     /// - **macOS**: It will always return [`Ok`].
+    /// - **Linux (X11)**: It will return `None` when the owner process is not running
+    ///   on this machine, which is checked by `WM_CLIENT_MACHINE` or the X-Resource extension.
+    /// - **Linux (Wayland)**: It will always return [`Error::Unsupported`](crate::Error::Unsupported).
     pub fn owner_name(&self) -> Result<Option<String>, Error> {
-        #[cfg(target_os = "macos")]
-        {
-            Ok(self.0.owner_name())
-        }
+        self.backend_window().owner_name()
+    }
 
-        #[cfg(target_os = "windows")]
-        {
-            Ok(self.0.owner_name().map(Some)?)
-        }
+    /// Returns the information of the process which owns the window.
+    /// See [`ProcessInfo`] for the fields.
+    ///
+    /// # Platform-specific
+    /// - **Windows**: The name and the executable are the same as [`owner_name`](Self::owner_name)
+    ///   and [`app_id`](Self::app_id). The parent process ID is read from
+    ///   `CreateToolhelp32Snapshot` and the start time by `GetProcessTimes`.
+    ///   The command line and the user ID are not provided.
+    /// - **macOS**: It will always return [`Ok`]. The parent process ID, the user ID and
+    ///   the start time are read by `proc_pidinfo`. The command line is not provided.
+    /// - **Linux (X11)**: It is read from `/proc/<pid>/{stat,exe,cmdline,status}` with
+    ///   [`owner_pid`](Self::owner_pid). See `read_process_info`.
+    ///   Only the process ID is provided when the owner process is not running on this machine.
+    /// - **Linux (Wayland)**: It will always return [`Error::Unsupported`](crate::Error::Unsupported).
+    pub fn owner_process(&self) -> Result<ProcessInfo, Error> {
+        self.backend_window().owner_process()
+    }
+
+    /// Returns the identity of the application which owns the window.
+    /// See [`AppIdentity`] for the fields provided on each platform.
+    ///
+    /// # Platform-specific
+    /// - **Windows**: The executable is read by [`QueryFullProcessImageNameW`][qfpin].
+    ///   If you don't have permission to access the owner process,
+    ///   it will return [`Error::PermissionDenied`](crate::Error::PermissionDenied).
+    /// - **macOS**: It will always return [`Ok`]. The identifier is `None` when the
+    ///   executable is not in an application bundle.
+    /// - **Linux (X11)**: It is read from `WM_CLASS`, and the executable is read from
+    ///   `/proc/<pid>/exe` with [`owner_pid`](Self::owner_pid).
+    /// - **Linux (Wayland)**: It will always return [`Ok`] with the application ID only.
+    ///
+    /// [qfpin]: https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-queryfullprocessimagenamew
+    pub fn app_id(&self) -> Result<AppIdentity, Error> {
+        self.backend_window().app_id()
+    }
+
+    /// Checks if the window has the keyboard focus.
+    /// See also [`get_foreground_window`](crate::get_foreground_window).
+    ///
+    /// # Platform-specific
+    /// - **Windows**: It will always return [`Ok`]. See also
+    ///   `WindowsWindow::is_foreground`.
+    /// - **macOS**: It checks if the window is the frontmost window on the screen in the normal
+    ///   window layer, which is only a heuristic. It retrieves the list of windows each time.
+    /// - **Linux (X11)**: It checks if the window is `_NET_ACTIVE_WINDOW`.
+    /// - **Linux (Wayland)**: It is the activated state of `zwlr_foreign_toplevel_manager_v1`.
+    ///   It will return [`Error::Unsupported`](crate::Error::Unsupported) when the compositor
+    ///   only supports `ext_foreign_toplevel_list_v1`.
+    pub fn is_focused(&self) -> Result<bool, Error> {
+        self.backend_window().is_focused()
+    }
+
+    /// Checks if the window is on the screen.
+    ///
+    /// It is `false` for the windows which are hidden, minimized or on the other workspaces.
+    ///
+    /// # Platform-specific
+    /// - **macOS**: It is `kCGWindowIsOnscreen` of the window information dictionary.
+    /// - **Windows**: The window is visible and not minimized.
+    /// - **Linux (X11)**: The window is viewable. The window managers usually unmap
+    ///   the windows which are minimized or on the other workspaces.
+    /// - **Linux (Wayland)**: The window is not minimized. It will return
+    ///   [`Error::Unsupported`](crate::Error::Unsupported) when the compositor only supports
+    ///   `ext_foreign_toplevel_list_v1`.
+    pub fn is_on_screen(&self) -> Result<bool, Error> {
+        self.backend_window().is_on_screen()
+    }
+
+    /// Returns the state of the window, e.g. whether it is minimized or fullscreen.
+    ///
+    /// # Platform-specific
+    /// - **Windows**: [`MINIMIZED`](WindowState::MINIMIZED) is `IsIconic`,
+    ///   [`MAXIMIZED`](WindowState::MAXIMIZED) is `IsZoomed` and [`HIDDEN`](WindowState::HIDDEN)
+    ///   is the opposite of `IsWindowVisible`. [`FULLSCREEN`](WindowState::FULLSCREEN) is
+    ///   a heuristic: the window is not maximized and covers its whole monitor.
+    /// - **macOS**: It will always return [`Error::Unsupported`](crate::Error::Unsupported),
+    ///   because the states are not provided without the accessibility permission.
+    ///   Use [`is_on_screen`](Self::is_on_screen) instead.
+    /// - **Linux (X11)**: [`MINIMIZED`](WindowState::MINIMIZED) is the iconic state of
+    ///   `WM_STATE`, and the other states are read from `_NET_WM_STATE`.
+    ///   [`MAXIMIZED`](WindowState::MAXIMIZED) requires both the vertical and
+    ///   horizontal maximization.
+    /// - **Linux (Wayland)**: The states are the ones of `zwlr_foreign_toplevel_manager_v1`,
+    ///   and [`HIDDEN`](WindowState::HIDDEN) is never reported. It will return
+    ///   [`Error::Unsupported`](crate::Error::Unsupported) when the compositor only supports
+    ///   `ext_foreign_toplevel_list_v1`.
+    pub fn state(&self) -> Result<WindowState, Error> {
+        self.backend_window().state()
+    }
+
+    /// Returns the type of the window, e.g. whether it is a dialog or a tooltip.
+    /// See also [`WindowQuery::applications_only`](crate::WindowQuery::applications_only).
+    ///
+    /// # Platform-specific
+    /// - **Windows**: It is guessed from the extended window styles. The shell's desktop window
+    ///   is [`Desktop`](WindowKind::Desktop), the windows with `WS_EX_TOOLWINDOW` are
+    ///   [`Utility`](WindowKind::Utility), or [`Tooltip`](WindowKind::Tooltip) if they are also
+    ///   topmost and transparent to the mouse, and the windows with `WS_EX_DLGMODALFRAME` or
+    ///   an owner window without `WS_EX_APPWINDOW` are [`Dialog`](WindowKind::Dialog).
+    /// - **macOS**: It is guessed from the layer (`MacOSWindow::layer`)
+    ///   of the window, so the panels in the normal layer are [`Normal`](WindowKind::Normal).
+    ///   It will always return [`Ok`].
+    /// - **Linux (X11)**: It is read from `_NET_WM_WINDOW_TYPE`. When it is not set,
+    ///   the window is [`Dialog`](WindowKind::Dialog) if it has `WM_TRANSIENT_FOR`
+    ///   and [`Normal`](WindowKind::Normal) otherwise, as defined by EWMH.
+    /// - **Linux (Wayland)**: The compositors only expose the top-level windows, so the
+    ///   window is [`Dialog`](WindowKind::Dialog) if it has a parent according to
+    ///   `zwlr_foreign_toplevel_manager_v1` and [`Normal`](WindowKind::Normal) otherwise.
+    ///   It will return [`Error::Unsupported`](crate::Error::Unsupported) when the compositor
+    ///   only supports `ext_foreign_toplevel_list_v1`, which does not provide the parent.
+    pub fn kind(&self) -> Result<WindowKind, Error> {
+        self.backend_window().kind()
+    }
+
+    /// Returns the ID of the parent window, e.g. the main window of a dialog.
+    ///
+    /// It returns `None` for the top-level windows which do not belong to another window.
+    /// The ID can be passed to [`get_window`](crate::get_window) to retrieve the window.
+    ///
+    /// # Platform-specific
+    /// - **Windows**: It is [`GetParent`][gp], so it is the owner window for
+    ///   the pop-up windows such as the dialogs.
+    /// - **macOS**: It will always return [`Error::Unsupported`](crate::Error::Unsupported).
+    /// - **Linux (X11)**: It is `WM_TRANSIENT_FOR`. For the windows which are not top-level,
+    ///   it is the parent by `XQueryTree`.
+    /// - **Linux (Wayland)**: It is the parent of `zwlr_foreign_toplevel_manager_v1`.
+    ///   It will return [`Error::Unsupported`](crate::Error::Unsupported) when the compositor
+    ///   only supports `ext_foreign_toplevel_list_v1`.
+    ///
+    /// [gp]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getparent
+    pub fn parent(&self) -> Result<Option<WindowId>, Error> {
+        self.backend_window().parent()
+    }
+
+    /// Returns the ID of the window which owns the window, e.g. the main window of a dialog.
+    ///
+    /// Unlike [`parent`](Self::parent), it is `None` for the child windows which are only
+    /// placed inside another window.
+    ///
+    /// # Platform-specific
+    /// - **Windows**: It is [`GetWindow`][gw] with `GW_OWNER`.
+    /// - **macOS**: It will always return [`Error::Unsupported`](crate::Error::Unsupported).
+    /// - **Linux (X11)**: It is `WM_TRANSIENT_FOR`.
+    /// - **Linux (Wayland)**: It is the same as [`parent`](Self::parent).
+    ///
+    /// [gw]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getwindow
+    pub fn owner_window(&self) -> Result<Option<WindowId>, Error> {
+        self.backend_window().owner_window()
+    }
+
+    /// Returns the IDs of the windows whose [parent](Self::parent) is the window.
+    ///
+    /// # Platform-specific
+    /// - **Windows**: The child windows and the top-level windows are enumerated,
+    ///   so it takes a while when there are many windows.
+    /// - **macOS**: It will always return [`Error::Unsupported`](crate::Error::Unsupported).
+    /// - **Linux (X11)**: They are the children by `XQueryTree` followed by the client windows
+    ///   whose `WM_TRANSIENT_FOR` is the window.
+    /// - **Linux (Wayland)**: The windows are retrieved again to find the ones whose parent
+    ///   is the window. It will return [`Error::Unsupported`](crate::Error::Unsupported)
+    ///   when the compositor only supports `ext_foreign_toplevel_list_v1`.
+    pub fn children(&self) -> Result<Vec<WindowId>, Error> {
+        self.backend_window().children()
+    }
+
+    /// Returns the monitor on which the window is. See [`get_monitors`](crate::get_monitors).
+    ///
+    /// When the window spans several monitors, it is the one which has the largest
+    /// overlap with the window. It returns `None` if the window is on no monitor.
+    ///
+    /// # Platform-specific
+    /// - **Windows**: It is the monitor returned by [`MonitorFromWindow`][mfw], which is
+    ///   the nearest one when the window is on no monitor.
+    /// - **Linux (Wayland)**: It is the first output the window entered according to
+    ///   `zwlr_foreign_toplevel_manager_v1`. It will return
+    ///   [`Error::Unsupported`](crate::Error::Unsupported) when the compositor only supports
+    ///   `ext_foreign_toplevel_list_v1`.
+    ///
+    /// [mfw]: https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-monitorfromwindow
+    pub fn monitor(&self) -> Result<Option<Monitor>, Error> {
+        self.backend_window().monitor()
+    }
+
+    /// Reads all the properties of the window at once into an owned [`WindowSnapshot`].
+    ///
+    /// The properties which could not be read are `None` in the snapshot
+    /// and their errors are stored in [`WindowSnapshot::errors`].
+    ///
+    /// # Platform-specific
+    /// - **macOS**: The properties are read from the window information dictionary which was
+    ///   fetched when the window was retrieved, so they are always consistent.
+    /// - **Windows**: The properties are read by separate system calls. If the window is
+    ///   destroyed or its handle is reused while they are read, all of them fail with
+    ///   [`Error::WindowNotFound`](crate::Error::WindowNotFound).
+    /// - **Linux (X11)**: The requests are answered by the X server in a single round trip.
+    pub fn snapshot(&self) -> WindowSnapshot {
+        self.backend_window().snapshot()
     }
 }
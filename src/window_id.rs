@@ -7,6 +7,16 @@ use crate::platform_impl::PlatformWindowId;
 /// - **Windows**: The ID is a value of [`HWND`][HWND].
 /// - **macOS**: The ID is a unique within the current user session.
 ///   It is called a window number and same as [`CGWindowID`][CGWindowID].
+/// - **Linux (X11)**: The ID is the XID of the window on the X server.
+/// - **Linux (Wayland)**: The ID is a hash of the identifier string sent by
+///   `ext_foreign_toplevel_list_v1`. The string itself is available from
+///   `WaylandWindow::identifier`.
+///   On `zwlr_foreign_toplevel_manager_v1`, which has no identifier, the ID is the object ID
+///   of the toplevel handle and it is only valid in the current process.
+///
+/// # Serialization
+/// With the `serde` feature, it is serialized with the name of the platform, e.g.
+/// `{"platform": "macos", "id": 42}`, and an ID of another platform cannot be deserialized.
 ///
 /// [HWND]: https://learn.microsoft.com/ja-jp/windows/win32/winprog/windows-data-types#HWND
 /// [CGWindowID]: https://developer.apple.com/documentation/coregraphics/cgwindowid?language=objc
@@ -36,8 +46,9 @@ impl WindowId {
     /// # Platform-specific
     /// - **macOS**: Returns the window number. It is same as [`WindowId::platform_window_id`].
     /// - **Windows**: Returns the window handle as a `u32`.
+    /// - **Linux**: Returns the XID of the window on X11 or the hashed identifier on Wayland.
     pub fn as_u32(&self) -> u32 {
-        #[cfg(target_os = "macos")]
+        #[cfg(any(target_os = "macos", target_os = "linux"))]
         {
             self.0
         }
@@ -50,7 +61,7 @@ impl WindowId {
 
 impl From<u32> for WindowId {
     fn from(id: u32) -> Self {
-        #[cfg(target_os = "macos")]
+        #[cfg(any(target_os = "macos", target_os = "linux"))]
         {
             Self(id)
         }
@@ -66,3 +77,50 @@ impl std::hash::Hash for WindowId {
         self.as_u32().hash(state);
     }
 }
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use std::borrow::Cow;
+
+    use serde::{Deserialize, Serialize, de::Error as _};
+
+    use super::WindowId;
+
+    /// The name of the platform that the window IDs belong to.
+    const PLATFORM: &str = std::env::consts::OS;
+
+    /// The serialized form of [`WindowId`].
+    /// The ID is tagged with the platform because its meaning depends on the platform.
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "WindowId")]
+    struct TaggedWindowId<'a> {
+        platform: Cow<'a, str>,
+        id: u32,
+    }
+
+    impl Serialize for WindowId {
+        fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            TaggedWindowId {
+                platform: Cow::Borrowed(PLATFORM),
+                id: self.as_u32(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for WindowId {
+        /// Deserializes a window ID. It fails if the ID belongs to another platform.
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let tagged = TaggedWindowId::deserialize(deserializer)?;
+
+            if tagged.platform != PLATFORM {
+                return Err(D::Error::custom(format!(
+                    "the window ID belongs to `{}`, but the current platform is `{PLATFORM}`",
+                    tagged.platform
+                )));
+            }
+
+            Ok(Self::from(tagged.id))
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use proptest::prelude::*;
+use window_getter::Bounds;
+
+/// Bounds with integer coordinates, so that the arithmetic is exact.
+fn bounds() -> impl Strategy<Value = Bounds> {
+    (-1000i32..1000, -1000i32..1000, 0u32..1000, 0u32..1000)
+        .prop_map(|(x, y, width, height)| Bounds::from((x, y, width, height)))
+}
+
+fn point() -> impl Strategy<Value = (f64, f64)> {
+    (-1500i32..1500, -1500i32..1500).prop_map(|(x, y)| (x.into(), y.into()))
+}
+
+proptest! {
+    #[test]
+    fn intersect_is_commutative(a in bounds(), b in bounds()) {
+        prop_assert_eq!(a.intersect(&b), b.intersect(&a));
+    }
+
+    #[test]
+    fn intersection_is_contained_in_both(a in bounds(), b in bounds()) {
+        if let Some(intersection) = a.intersect(&b) {
+            prop_assert!(a.contains(&intersection));
+            prop_assert!(b.contains(&intersection));
+            prop_assert!(intersection.area() <= a.area().min(b.area()));
+        }
+    }
+
+    #[test]
+    fn intersection_contains_the_points_in_both(a in bounds(), b in bounds(), (x, y) in point()) {
+        let in_both = a.contains_point(x, y) && b.contains_point(x, y);
+        let in_intersection = a.intersect(&b).is_some_and(|bounds| bounds.contains_point(x, y));
+
+        prop_assert_eq!(in_both, in_intersection);
+    }
+
+    #[test]
+    fn union_is_commutative(a in bounds(), b in bounds()) {
+        prop_assert_eq!(a.union(&b), b.union(&a));
+    }
+
+    #[test]
+    fn union_contains_both(a in bounds(), b in bounds(), (x, y) in point()) {
+        let union = a.union(&b);
+
+        prop_assert!(union.contains(&a));
+        prop_assert!(union.contains(&b));
+        if a.contains_point(x, y) || b.contains_point(x, y) {
+            prop_assert!(union.contains_point(x, y));
+        }
+    }
+
+    #[test]
+    fn union_with_contained_bounds_is_identity(a in bounds(), b in bounds()) {
+        if a.contains(&b) && !a.is_empty() {
+            prop_assert_eq!(a.union(&b), a);
+        }
+    }
+
+    #[test]
+    fn bounds_contain_themselves(a in bounds()) {
+        prop_assert!(a.contains(&a));
+        prop_assert_eq!(a.intersect(&a).is_some(), !a.is_empty());
+    }
+
+    #[test]
+    fn empty_bounds_contain_no_points(a in bounds(), (x, y) in point()) {
+        if a.is_empty() {
+            prop_assert!(!a.contains_point(x, y));
+            prop_assert_eq!(a.area(), 0.0);
+        }
+    }
+
+    #[test]
+    fn subtract_conserves_the_area(a in bounds(), b in bounds()) {
+        let parts = a.subtract(&b);
+        let covered = a.intersect(&b).map_or(0.0, |bounds| bounds.area());
+
+        prop_assert_eq!(parts.iter().map(Bounds::area).sum::<f64>() + covered, a.area());
+    }
+
+    #[test]
+    fn subtract_covers_the_uncovered_points(a in bounds(), b in bounds(), (x, y) in point()) {
+        let parts = a.subtract(&b);
+        let count = parts.iter().filter(|part| part.contains_point(x, y)).count();
+        let uncovered = a.contains_point(x, y) && !b.contains_point(x, y);
+
+        prop_assert_eq!(count, usize::from(uncovered));
+    }
+
+    #[test]
+    fn translate_moves_points(a in bounds(), (dx, dy) in point(), (x, y) in point()) {
+        let moved = a.translate(dx, dy);
+
+        prop_assert_eq!(moved.area(), a.area());
+        prop_assert_eq!(moved.contains_point(x + dx, y + dy), a.contains_point(x, y));
+        prop_assert_eq!(moved.translate(-dx, -dy), a);
+    }
+
+    #[test]
+    fn inset_keeps_the_center(a in bounds(), dx in -500i32..500, dy in -500i32..500) {
+        let inset = a.inset(dx.into(), dy.into());
+
+        prop_assert_eq!(inset.center(), a.center());
+        prop_assert!(inset.width >= 0.0 && inset.height >= 0.0);
+        if dx >= 0 && dy >= 0 {
+            prop_assert!(a.contains(&inset));
+        } else if dx <= 0 && dy <= 0 {
+            prop_assert!(inset.contains(&a));
+        }
+    }
+
+    #[test]
+    fn integer_bounds_round_trip(a in bounds()) {
+        prop_assert_eq!(Bounds::from(a.round_out()), a.clone());
+        prop_assert_eq!(Bounds::from(a.round()), a);
+    }
+
+    #[test]
+    fn round_out_contains_the_bounds(
+        a in bounds(),
+        offset in (0.0f64..1.0, 0.0f64..1.0, 0.0f64..1.0, 0.0f64..1.0),
+    ) {
+        let (dx, dy, dw, dh) = offset;
+        let a = Bounds::new(a.x + dx, a.y + dy, a.width + dw, a.height + dh);
+
+        prop_assert!(Bounds::from(a.round_out()).contains(&a));
+    }
+}
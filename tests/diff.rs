@@ -0,0 +1,124 @@
+use window_getter::{Bounds, Change, Error, WindowSnapshot, diff_snapshots};
+
+fn snapshot(id: u32, title: &str) -> WindowSnapshot {
+    WindowSnapshot::from_results(
+        id.into(),
+        Ok(Some(title.into())),
+        Ok(Bounds::new(0., 0., 100., 100.)),
+        Ok(100),
+        Ok(Some("app".into())),
+    )
+}
+
+#[test]
+fn added_removed_and_changed_windows() {
+    let old = [snapshot(1, "a"), snapshot(2, "b"), snapshot(3, "c")];
+    let mut moved = snapshot(3, "c");
+    moved.bounds = Some(Bounds::new(10., 0., 100., 100.));
+    let new = [snapshot(4, "d"), snapshot(1, "a - edited"), moved];
+
+    let diff = diff_snapshots(&old, &new);
+
+    assert_eq!(
+        diff.added.iter().map(|s| s.id).collect::<Vec<_>>(),
+        [4.into()]
+    );
+    assert_eq!(
+        diff.removed.iter().map(|s| s.id).collect::<Vec<_>>(),
+        [2.into()]
+    );
+    assert_eq!(diff.changed.len(), 2);
+
+    let title = &diff.changed[0];
+    assert_eq!(title.id, 1.into());
+    assert_eq!(
+        title.title,
+        Some(Change {
+            old: Some("a".into()),
+            new: Some("a - edited".into()),
+        })
+    );
+    assert_eq!(title.bounds, None);
+    assert_eq!(title.position, None);
+
+    let bounds = &diff.changed[1];
+    assert_eq!(bounds.id, 3.into());
+    assert_eq!(bounds.title, None);
+    assert_eq!(
+        bounds.bounds.as_ref().map(|change| &change.new),
+        Some(&Some(Bounds::new(10., 0., 100., 100.)))
+    );
+}
+
+#[test]
+fn reordered_windows_change_their_positions() {
+    let old = [snapshot(1, "a"), snapshot(2, "b")];
+    let new = [snapshot(2, "b"), snapshot(1, "a")];
+
+    let diff = diff_snapshots(&old, &new);
+
+    let positions = diff
+        .changed
+        .iter()
+        .map(|change| (change.id, change.position.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        positions,
+        [
+            (2.into(), Some(Change { old: 1, new: 0 })),
+            (1.into(), Some(Change { old: 0, new: 1 })),
+        ]
+    );
+    assert!(diff_snapshots(&new, &new).is_empty());
+}
+
+#[test]
+fn unreadable_properties_are_not_changes() {
+    let old = [snapshot(1, "a")];
+    let new = [WindowSnapshot::from_results(
+        1.into(),
+        Err(Error::Unsupported("title")),
+        Ok(Bounds::new(0., 0., 100., 100.)),
+        Ok(100),
+        Ok(None),
+    )];
+
+    let diff = diff_snapshots(&old, &new);
+
+    assert_eq!(diff.changed.len(), 1);
+    assert_eq!(diff.changed[0].title, None);
+    assert_eq!(
+        diff.changed[0].owner_name,
+        Some(Change {
+            old: Some("app".into()),
+            new: None,
+        })
+    );
+    assert!(diff_snapshots(&new, &old).changed[0].title.is_none());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialized_snapshots_keep_the_errors() {
+    let old = [snapshot(1, "a")];
+    let new = [WindowSnapshot::from_error(
+        1.into(),
+        Error::WindowNotFound(1.into()),
+    )];
+
+    let json = serde_json::to_string(&new).unwrap();
+    let restored: Vec<WindowSnapshot> = serde_json::from_str(&json).unwrap();
+
+    let (name, error) = restored[0].errors.iter().next().unwrap();
+    assert_eq!(name, "title");
+    assert!(
+        matches!(error, Error::Recorded(message) if message == "The window `1` does not exist.")
+    );
+    assert!(diff_snapshots(&old, &restored).is_empty());
+    assert!(
+        serde_json::from_str::<WindowSnapshot>(&serde_json::to_string(&old[0]).unwrap())
+            .unwrap()
+            .errors
+            .is_empty()
+    );
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "mock")]
+
+use window_getter::{
+    WindowBackend,
+    mock::{MockDesktop, MockWindow},
+};
+
+#[test]
+fn dialogs_are_children_of_their_parent() {
+    let desktop = MockDesktop::new()
+        .with_window(MockWindow::new(1))
+        .with_window(MockWindow::new(2).parent(1).owner_window(1))
+        .with_window(MockWindow::new(3).parent(2))
+        .with_window(MockWindow::new(4).parent(1));
+
+    let window = |id: u32| desktop.get_window(id.into()).unwrap().unwrap();
+
+    assert_eq!(window(1).parent().unwrap(), None);
+    assert_eq!(window(2).parent().unwrap(), Some(1.into()));
+    assert_eq!(window(3).owner_window().unwrap(), None);
+    assert_eq!(window(1).children().unwrap(), [2.into(), 4.into()]);
+    assert_eq!(window(2).children().unwrap(), [3.into()]);
+    assert!(window(4).children().unwrap().is_empty());
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "mock")]
+
+use window_getter::{
+    WindowKind, WindowQuery,
+    mock::{MockDesktop, MockWindow},
+};
+
+#[test]
+fn applications_only_skips_the_windows_of_the_desktop() {
+    let desktop = MockDesktop::new()
+        .with_window(MockWindow::new(1).kind(WindowKind::Tooltip))
+        .with_window(MockWindow::new(2))
+        .with_window(MockWindow::new(3).kind(WindowKind::Dialog))
+        .with_window(MockWindow::new(4).kind(WindowKind::Dock))
+        .with_window(MockWindow::new(5).kind(WindowKind::Desktop));
+
+    let ids = WindowQuery::new()
+        .applications_only()
+        .find_all_in(&desktop)
+        .unwrap()
+        .iter()
+        .map(|window| window.id().as_u32())
+        .collect::<Vec<_>>();
+
+    assert_eq!(ids, [2, 3]);
+}
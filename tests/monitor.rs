@@ -0,0 +1,46 @@
+use window_getter::{Bounds, CoordinateSpace, Monitor};
+
+fn monitor(x: f64, scale_factor: f64) -> Monitor {
+    let bounds = Bounds::new(x, 0.0, 1920.0, 1080.0);
+
+    Monitor {
+        id: 1,
+        name: None,
+        work_area: bounds.clone(),
+        bounds,
+        scale_factor,
+        is_primary: false,
+        coordinate_space: CoordinateSpace::Logical,
+    }
+}
+
+#[test]
+fn conversion_keeps_the_origin_of_the_monitor() {
+    let monitor = monitor(1920.0, 2.0);
+    let window = Bounds::new(2020.0, 50.0, 300.0, 200.0);
+
+    let physical = monitor.convert(&window, CoordinateSpace::Physical);
+    assert_eq!(physical, Bounds::new(2120.0, 100.0, 600.0, 400.0));
+    assert_eq!(
+        monitor.bounds_in(CoordinateSpace::Physical),
+        Bounds::new(1920.0, 0.0, 3840.0, 2160.0)
+    );
+
+    let physical_monitor = Monitor {
+        bounds: monitor.bounds_in(CoordinateSpace::Physical),
+        coordinate_space: CoordinateSpace::Physical,
+        ..monitor.clone()
+    };
+    assert_eq!(
+        physical_monitor.convert(&physical, CoordinateSpace::Logical),
+        window
+    );
+}
+
+#[test]
+fn conversion_to_the_same_space_does_nothing() {
+    let monitor = monitor(0.0, 1.5);
+    let window = Bounds::new(10.0, 20.0, 30.0, 40.0);
+
+    assert_eq!(monitor.convert(&window, CoordinateSpace::Logical), window);
+}
@@ -0,0 +1,94 @@
+#![cfg(target_os = "linux")]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use window_getter::platform_impl::linux::read_process_info;
+
+/// Creates a fake procfs root with the files of the process `42`.
+fn fake_proc_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!(
+        "window-getter-procfs-{name}-{}",
+        std::process::id()
+    ));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("42")).unwrap();
+
+    fs::write(root.join("stat"), "cpu  1 2 3 4\nbtime 1700000000\n").unwrap();
+    fs::write(
+        root.join("42/stat"),
+        "42 (my (odd) app) S 7 42 42 0 -1 4194560 100 0 0 0 1 2 0 0 20 0 1 0 0 1024 10\n",
+    )
+    .unwrap();
+
+    root
+}
+
+fn cleanup(root: &Path) {
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn reads_all_the_files() {
+    let root = fake_proc_root("all");
+    fs::write(root.join("42/cmdline"), b"/usr/bin/app\0--flag\0a b\0").unwrap();
+    fs::write(
+        root.join("42/status"),
+        "Name:\tapp\nPPid:\t7\nUid:\t1000\t1001\t1000\t1000\n",
+    )
+    .unwrap();
+    std::os::unix::fs::symlink("/usr/bin/app", root.join("42/exe")).unwrap();
+
+    let info = read_process_info(&root, 42).unwrap();
+    cleanup(&root);
+
+    assert_eq!(info.pid, 42);
+    assert_eq!(info.name.as_deref(), Some("my (odd) app"));
+    assert_eq!(info.parent_pid, Some(7));
+    assert_eq!(info.executable, Some(PathBuf::from("/usr/bin/app")));
+    assert_eq!(
+        info.command_line,
+        Some(vec!["/usr/bin/app".into(), "--flag".into(), "a b".into()])
+    );
+    assert_eq!(info.user_id, Some(1000));
+    // The process started at the boot because its start time is `0` ticks.
+    assert_eq!(
+        info.start_time,
+        Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+    );
+}
+
+#[test]
+fn empty_arguments_are_kept() {
+    let root = fake_proc_root("empty-arguments");
+    fs::write(root.join("42/cmdline"), b"prog\0\0x\0").unwrap();
+
+    let info = read_process_info(&root, 42).unwrap();
+    fs::write(root.join("42/cmdline"), b"").unwrap();
+    let kernel_thread = read_process_info(&root, 42).unwrap();
+    cleanup(&root);
+
+    assert_eq!(
+        info.command_line,
+        Some(vec!["prog".into(), "".into(), "x".into()])
+    );
+    assert_eq!(kernel_thread.command_line, Some(Vec::new()));
+}
+
+#[test]
+fn optional_files_may_be_missing() {
+    let root = fake_proc_root("missing");
+
+    let info = read_process_info(&root, 42).unwrap();
+    let missing = read_process_info(&root, 43);
+    cleanup(&root);
+
+    assert_eq!(info.parent_pid, Some(7));
+    assert_eq!(info.executable, None);
+    assert_eq!(info.command_line, None);
+    assert_eq!(info.user_id, None);
+    assert!(missing.is_err());
+}
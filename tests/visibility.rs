@@ -0,0 +1,45 @@
+#![cfg(feature = "mock")]
+
+use window_getter::{
+    Bounds, WindowBackend, compute_visibility,
+    mock::{MockDesktop, MockWindow},
+};
+
+#[test]
+fn windows_are_covered_by_the_windows_above() {
+    let desktop = MockDesktop::new()
+        .with_window(
+            MockWindow::new(1)
+                .z_index(3)
+                .bounds(Bounds::new(0.0, 0.0, 50.0, 100.0)),
+        )
+        .with_window(
+            MockWindow::new(2)
+                .z_index(2)
+                .bounds(Bounds::new(0.0, 0.0, 100.0, 100.0)),
+        )
+        .with_window(
+            MockWindow::new(3)
+                .z_index(1)
+                .bounds(Bounds::new(50.0, 50.0, 100.0, 100.0))
+                .on_screen(false),
+        )
+        .with_window(
+            MockWindow::new(4)
+                .z_index(0)
+                .bounds(Bounds::new(0.0, 0.0, 200.0, 100.0)),
+        );
+
+    let visibility = compute_visibility(&desktop.get_windows().unwrap());
+    let fractions = visibility
+        .iter()
+        .map(|visibility| visibility.visible_fraction)
+        .collect::<Vec<_>>();
+
+    assert_eq!(fractions, [Some(1.0), Some(0.5), Some(0.0), Some(0.5)]);
+    assert_eq!(
+        visibility[3].visible_region,
+        [Bounds::new(100.0, 0.0, 100.0, 100.0)]
+    );
+    assert!(visibility[2].is_hidden());
+}
@@ -0,0 +1,395 @@
+//! The tests of the Wayland backend against a compositor running in this process,
+//! which only announces the toplevels through the foreign toplevel protocols.
+#![cfg(target_os = "linux")]
+
+use std::sync::{Arc, Mutex, OnceLock, mpsc};
+
+use wayland_protocols::ext::foreign_toplevel_list::v1::server::{
+    ext_foreign_toplevel_handle_v1::{self, ExtForeignToplevelHandleV1},
+    ext_foreign_toplevel_list_v1::{self, ExtForeignToplevelListV1},
+};
+use wayland_protocols_wlr::foreign_toplevel::v1::server::{
+    zwlr_foreign_toplevel_handle_v1::{self, ZwlrForeignToplevelHandleV1},
+    zwlr_foreign_toplevel_manager_v1::{self, ZwlrForeignToplevelManagerV1},
+};
+use wayland_server::{
+    Client, DataInit, Dispatch, Display, DisplayHandle, GlobalDispatch, ListeningSocket, New,
+    Resource,
+    backend::{ClientData, ClientId, DisconnectReason},
+};
+use window_getter::{
+    Error, Window, WindowKind,
+    platform_impl::wayland::{self, WaylandProtocol},
+};
+
+type Command = Box<dyn FnOnce(&mut Compositor, &DisplayHandle) + Send>;
+
+/// The toplevels and the protocol objects bound by the clients.
+#[derive(Default)]
+struct Compositor {
+    ext_lists: Vec<ExtForeignToplevelListV1>,
+    wlr_managers: Vec<ZwlrForeignToplevelManagerV1>,
+    toplevels: Vec<Toplevel>,
+}
+
+struct Toplevel {
+    identifier: String,
+    title: String,
+    app_id: String,
+    activated: bool,
+    parent: Option<String>,
+    ext_handles: Vec<ExtForeignToplevelHandleV1>,
+    wlr_handles: Vec<ZwlrForeignToplevelHandleV1>,
+}
+
+impl Toplevel {
+    fn new(identifier: &str, title: &str, app_id: &str) -> Self {
+        Self {
+            identifier: identifier.into(),
+            title: title.into(),
+            app_id: app_id.into(),
+            activated: false,
+            parent: None,
+            ext_handles: Vec::new(),
+            wlr_handles: Vec::new(),
+        }
+    }
+}
+
+impl Compositor {
+    fn add(&mut self, dh: &DisplayHandle, toplevel: Toplevel) {
+        self.toplevels.push(toplevel);
+        let index = self.toplevels.len() - 1;
+
+        for list in self.ext_lists.clone() {
+            self.announce_ext(dh, &list, index);
+        }
+        for manager in self.wlr_managers.clone() {
+            self.announce_wlr(dh, &manager, index);
+        }
+    }
+
+    fn close(&mut self, identifier: &str) {
+        let index = self.position(identifier);
+        let toplevel = self.toplevels.remove(index);
+
+        for handle in toplevel.ext_handles {
+            handle.closed();
+        }
+        for handle in toplevel.wlr_handles {
+            handle.closed();
+        }
+    }
+
+    fn set_title(&mut self, identifier: &str, title: &str) {
+        let index = self.position(identifier);
+        let toplevel = &mut self.toplevels[index];
+        toplevel.title = title.into();
+
+        for handle in &toplevel.ext_handles {
+            handle.title(title.into());
+            handle.done();
+        }
+        for handle in &toplevel.wlr_handles {
+            handle.title(title.into());
+            handle.done();
+        }
+    }
+
+    fn position(&self, identifier: &str) -> usize {
+        self.toplevels
+            .iter()
+            .position(|toplevel| toplevel.identifier == identifier)
+            .unwrap()
+    }
+
+    fn announce_ext(&mut self, dh: &DisplayHandle, list: &ExtForeignToplevelListV1, index: usize) {
+        let Some(client) = list.client() else {
+            return;
+        };
+        let handle = client
+            .create_resource::<ExtForeignToplevelHandleV1, (), Self>(dh, list.version(), ())
+            .unwrap();
+        let toplevel = &mut self.toplevels[index];
+
+        list.toplevel(&handle);
+        handle.identifier(toplevel.identifier.clone());
+        handle.title(toplevel.title.clone());
+        handle.app_id(toplevel.app_id.clone());
+        handle.done();
+        toplevel.ext_handles.push(handle);
+    }
+
+    fn announce_wlr(
+        &mut self,
+        dh: &DisplayHandle,
+        manager: &ZwlrForeignToplevelManagerV1,
+        index: usize,
+    ) {
+        let Some(client) = manager.client() else {
+            return;
+        };
+        let handle = client
+            .create_resource::<ZwlrForeignToplevelHandleV1, (), Self>(dh, manager.version(), ())
+            .unwrap();
+        let parent = self.toplevels[index].parent.as_deref().and_then(|parent| {
+            let parent = &self.toplevels[self.position(parent)];
+            parent
+                .wlr_handles
+                .iter()
+                .find(|parent| parent.id().same_client_as(&manager.id()))
+                .cloned()
+        });
+        let toplevel = &mut self.toplevels[index];
+
+        manager.toplevel(&handle);
+        handle.title(toplevel.title.clone());
+        handle.app_id(toplevel.app_id.clone());
+        let state = if toplevel.activated {
+            let activated = zwlr_foreign_toplevel_handle_v1::State::Activated as u32;
+            activated.to_ne_bytes().to_vec()
+        } else {
+            Vec::new()
+        };
+        handle.state(state);
+        if parent.is_some() {
+            handle.parent(parent.as_ref());
+        }
+        handle.done();
+        toplevel.wlr_handles.push(handle);
+    }
+}
+
+impl GlobalDispatch<ExtForeignToplevelListV1, ()> for Compositor {
+    fn bind(
+        state: &mut Self,
+        dh: &DisplayHandle,
+        _client: &Client,
+        resource: New<ExtForeignToplevelListV1>,
+        _global_data: &(),
+        data_init: &mut DataInit<'_, Self>,
+    ) {
+        let list = data_init.init(resource, ());
+        for index in 0..state.toplevels.len() {
+            state.announce_ext(dh, &list, index);
+        }
+        state.ext_lists.push(list);
+    }
+}
+
+impl GlobalDispatch<ZwlrForeignToplevelManagerV1, ()> for Compositor {
+    fn bind(
+        state: &mut Self,
+        dh: &DisplayHandle,
+        _client: &Client,
+        resource: New<ZwlrForeignToplevelManagerV1>,
+        _global_data: &(),
+        data_init: &mut DataInit<'_, Self>,
+    ) {
+        let manager = data_init.init(resource, ());
+        for index in 0..state.toplevels.len() {
+            state.announce_wlr(dh, &manager, index);
+        }
+        state.wlr_managers.push(manager);
+    }
+}
+
+macro_rules! ignore_requests {
+    ($($interface:ty => $request:ty),* $(,)?) => {
+        $(
+            impl Dispatch<$interface, ()> for Compositor {
+                fn request(
+                    _state: &mut Self,
+                    _client: &Client,
+                    _resource: &$interface,
+                    _request: $request,
+                    _data: &(),
+                    _dh: &DisplayHandle,
+                    _data_init: &mut DataInit<'_, Self>,
+                ) {
+                }
+            }
+        )*
+    };
+}
+
+ignore_requests! {
+    ExtForeignToplevelListV1 => ext_foreign_toplevel_list_v1::Request,
+    ExtForeignToplevelHandleV1 => ext_foreign_toplevel_handle_v1::Request,
+    ZwlrForeignToplevelManagerV1 => zwlr_foreign_toplevel_manager_v1::Request,
+    ZwlrForeignToplevelHandleV1 => zwlr_foreign_toplevel_handle_v1::Request,
+}
+
+struct NoClientData;
+
+impl ClientData for NoClientData {
+    fn initialized(&self, _client_id: ClientId) {}
+    fn disconnected(&self, _client_id: ClientId, _reason: DisconnectReason) {}
+}
+
+/// Runs `f` on the compositor, starting it for the whole process at the first call.
+/// The events sent by `f` are received by the next call of the backend.
+fn with_compositor(f: impl FnOnce(&mut Compositor, &DisplayHandle) + Send + 'static) {
+    static COMMANDS: OnceLock<Mutex<mpsc::Sender<Command>>> = OnceLock::new();
+
+    let commands = COMMANDS.get_or_init(|| {
+        // The socket name is chosen from the free ones, and the stale sockets are reused.
+        let runtime_dir = std::env::temp_dir().join("window-getter-wayland");
+        std::fs::create_dir_all(&runtime_dir).unwrap();
+        // SAFETY: The variables are set before the compositor and the backend start,
+        // and no other code of the tests reads the environment.
+        unsafe { std::env::set_var("XDG_RUNTIME_DIR", &runtime_dir) };
+        let socket = ListeningSocket::bind_auto("wayland", 1..64).unwrap();
+        unsafe { std::env::set_var("WAYLAND_DISPLAY", socket.socket_name().unwrap()) };
+
+        let (sender, receiver) = mpsc::channel::<Command>();
+
+        std::thread::spawn(move || {
+            let mut display = Display::<Compositor>::new().unwrap();
+            let dh = display.handle();
+            dh.create_global::<Compositor, ExtForeignToplevelListV1, ()>(1, ());
+            dh.create_global::<Compositor, ZwlrForeignToplevelManagerV1, ()>(3, ());
+            let mut state = Compositor::default();
+
+            loop {
+                if let Some(stream) = socket.accept().unwrap() {
+                    dh.clone()
+                        .insert_client(stream, Arc::new(NoClientData))
+                        .unwrap();
+                }
+                while let Ok(command) = receiver.try_recv() {
+                    command(&mut state, &dh);
+                }
+                display.dispatch_clients(&mut state).unwrap();
+                display.flush_clients().unwrap();
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+        });
+
+        Mutex::new(sender)
+    });
+
+    let (done, wait) = mpsc::channel();
+    commands
+        .lock()
+        .unwrap()
+        .send(Box::new(move |state, dh| {
+            f(state, dh);
+            done.send(()).unwrap();
+        }))
+        .unwrap();
+    wait.recv().unwrap();
+}
+
+/// Retrieves the windows of the application through the protocol.
+fn windows_of(app_id: &str, protocol: WaylandProtocol) -> Vec<Window> {
+    wayland::get_windows_with(Some(protocol))
+        .unwrap()
+        .into_iter()
+        .filter(|window| {
+            window
+                .app_id()
+                .is_ok_and(|identity| identity.id.as_deref() == Some(app_id))
+        })
+        .collect()
+}
+
+fn titles(windows: &[Window]) -> Vec<String> {
+    windows
+        .iter()
+        .map(|window| window.title().unwrap().unwrap())
+        .collect()
+}
+
+#[test]
+fn ext_toplevels_are_tracked() {
+    const APP_ID: &str = "org.example.Ext";
+    with_compositor(|compositor, dh| {
+        compositor.add(dh, Toplevel::new("ext-1", "First", APP_ID));
+        compositor.add(dh, Toplevel::new("ext-2", "Second", APP_ID));
+    });
+
+    let windows = windows_of(APP_ID, WaylandProtocol::ExtForeignToplevelList);
+    assert_eq!(titles(&windows), ["First", "Second"]);
+    assert!(matches!(windows[0].bounds(), Err(Error::Unsupported(_))));
+    assert!(matches!(windows[0].owner_pid(), Err(Error::Unsupported(_))));
+    assert!(matches!(windows[0].kind(), Err(Error::Unsupported(_))));
+
+    let second = windows[1].id();
+    let found = wayland::get_window_with(
+        second.as_u32(),
+        Some(WaylandProtocol::ExtForeignToplevelList),
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(found.title().unwrap().as_deref(), Some("Second"));
+
+    with_compositor(|compositor, dh| {
+        compositor.close("ext-1");
+        compositor.set_title("ext-2", "Second - edited");
+        compositor.add(dh, Toplevel::new("ext-3", "Third", APP_ID));
+    });
+
+    let windows = windows_of(APP_ID, WaylandProtocol::ExtForeignToplevelList);
+    assert_eq!(titles(&windows), ["Second - edited", "Third"]);
+    assert_eq!(windows[0].id(), second);
+}
+
+#[test]
+fn ext_toplevels_with_the_same_hash_have_different_ids() {
+    const APP_ID: &str = "org.example.Collision";
+    // The identifiers have the same 32-bit FNV-1a hash.
+    with_compositor(|compositor, dh| {
+        compositor.add(dh, Toplevel::new("collision-568819", "First", APP_ID));
+        compositor.add(dh, Toplevel::new("collision-2156904", "Second", APP_ID));
+    });
+
+    let windows = windows_of(APP_ID, WaylandProtocol::ExtForeignToplevelList);
+    assert_eq!(titles(&windows), ["First", "Second"]);
+    assert_ne!(windows[0].id(), windows[1].id());
+
+    for window in &windows {
+        let found = wayland::get_window_with(
+            window.id().as_u32(),
+            Some(WaylandProtocol::ExtForeignToplevelList),
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(found.title().unwrap(), window.title().unwrap());
+    }
+}
+
+#[test]
+fn wlr_toplevels_have_the_state_and_the_parent() {
+    const APP_ID: &str = "org.example.Wlr";
+    with_compositor(|compositor, dh| {
+        let mut main = Toplevel::new("wlr-main", "Main", APP_ID);
+        main.activated = true;
+        let mut dialog = Toplevel::new("wlr-dialog", "Dialog", APP_ID);
+        dialog.parent = Some("wlr-main".into());
+
+        compositor.add(dh, main);
+        compositor.add(dh, dialog);
+    });
+
+    let windows = windows_of(APP_ID, WaylandProtocol::WlrForeignToplevelManagement);
+    assert_eq!(titles(&windows), ["Main", "Dialog"]);
+    let (main, dialog) = (&windows[0], &windows[1]);
+
+    assert!(main.is_focused().unwrap());
+    assert!(!dialog.is_focused().unwrap());
+    assert_eq!(dialog.parent().unwrap(), Some(main.id()));
+    assert_eq!(dialog.kind().unwrap(), WindowKind::Dialog);
+    assert_eq!(main.kind().unwrap(), WindowKind::Normal);
+
+    let foreground =
+        wayland::get_foreground_window_with(Some(WaylandProtocol::WlrForeignToplevelManagement))
+            .unwrap()
+            .unwrap();
+    assert_eq!(foreground.id(), main.id());
+
+    with_compositor(|compositor, _| compositor.close("wlr-dialog"));
+
+    let windows = windows_of(APP_ID, WaylandProtocol::WlrForeignToplevelManagement);
+    assert_eq!(titles(&windows), ["Main"]);
+}
@@ -0,0 +1,292 @@
+//! The tests with `#[ignore]` require an X server supporting the X-Resource extension,
+//! e.g. `xvfb-run cargo test --test x11 -- --ignored`.
+#![cfg(target_os = "linux")]
+
+use window_getter::{
+    Bounds, WindowQuery,
+    platform_impl::x11::{
+        self, X11Bounds, X11Connection, X11PidSource, X11Window,
+        window::{
+            extend_by_frame, is_same_host, parse_client_machine, parse_frame_extents, parse_pid,
+            parse_transient_for, parse_wm_class,
+        },
+    },
+};
+use x11rb::{
+    COPY_DEPTH_FROM_PARENT,
+    connection::Connection,
+    protocol::xproto::{
+        AtomEnum, ConnectionExt as _, CreateWindowAux, GetPropertyReply, PropMode, WindowClass,
+    },
+    rust_connection::RustConnection,
+    wrapper::ConnectionExt as _,
+};
+
+fn property8(value: &[u8]) -> GetPropertyReply {
+    GetPropertyReply {
+        format: 8,
+        value: value.to_vec(),
+        ..Default::default()
+    }
+}
+
+fn property32(values: &[u32]) -> GetPropertyReply {
+    GetPropertyReply {
+        format: 32,
+        value: values
+            .iter()
+            .flat_map(|value| value.to_ne_bytes())
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn parses_the_properties() {
+    assert_eq!(parse_pid(&property32(&[42])).unwrap(), 42);
+    assert!(parse_pid(&GetPropertyReply::default()).is_err());
+
+    assert_eq!(
+        parse_wm_class(&property8(b"navigator\0Firefox\0")),
+        Some(("navigator".into(), "Firefox".into()))
+    );
+    assert_eq!(parse_wm_class(&property8(b"navigator")), None);
+
+    assert_eq!(
+        parse_client_machine(&property8(b"host.example.com\0")).as_deref(),
+        Some("host.example.com")
+    );
+    assert_eq!(parse_client_machine(&property8(b"")), None);
+
+    assert_eq!(parse_transient_for(&property32(&[7]), 1), Some(7));
+    assert_eq!(parse_transient_for(&property32(&[1]), 1), None);
+    assert_eq!(parse_transient_for(&property32(&[0]), 1), None);
+}
+
+#[test]
+fn frame_extents_extend_the_rect() {
+    let rect = X11Bounds {
+        x: 10,
+        y: 20,
+        width: 300,
+        height: 200,
+    };
+
+    let frame_extents = parse_frame_extents(&property32(&[1, 2, 30, 4]));
+    assert_eq!(frame_extents, Some([1, 2, 30, 4]));
+    assert_eq!(
+        Bounds::from(extend_by_frame(rect, frame_extents)),
+        Bounds::new(9., -10., 303., 234.)
+    );
+    assert_eq!(
+        Bounds::from(extend_by_frame(rect, None)),
+        Bounds::from(rect)
+    );
+    assert_eq!(parse_frame_extents(&property32(&[1, 2])), None);
+}
+
+#[test]
+fn host_names_are_compared_by_their_short_names() {
+    assert!(is_same_host("host", "HOST"));
+    assert!(is_same_host("host", "host.example.com"));
+    assert!(is_same_host("host.example.com", "host"));
+    assert!(!is_same_host("host", "hostname"));
+    assert!(!is_same_host("host.example.com", "other.example.com"));
+}
+
+/// A client which shows a window on the X server.
+struct Client {
+    connection: RustConnection,
+    window: u32,
+}
+
+impl Client {
+    /// Maps a window at `(10, 20)` with the size of `300x200`.
+    /// `_NET_WM_PID` and `WM_CLIENT_MACHINE` are only set if `net_wm_pid` is `true`.
+    fn spawn(net_wm_pid: bool) -> Self {
+        let (connection, screen) = RustConnection::connect(None).unwrap();
+        let root = connection.setup().roots[screen].root;
+        let window = connection.generate_id().unwrap();
+
+        connection
+            .create_window(
+                COPY_DEPTH_FROM_PARENT,
+                window,
+                root,
+                10,
+                20,
+                300,
+                200,
+                0,
+                WindowClass::INPUT_OUTPUT,
+                0,
+                &CreateWindowAux::new(),
+            )
+            .unwrap();
+        connection
+            .change_property8(
+                PropMode::REPLACE,
+                window,
+                AtomEnum::WM_NAME,
+                AtomEnum::STRING,
+                b"window-getter",
+            )
+            .unwrap();
+
+        let client = Self { connection, window };
+        if net_wm_pid {
+            let hostname = hostname();
+            client.set_property32("_NET_WM_PID", AtomEnum::CARDINAL, &[std::process::id()]);
+            client
+                .connection
+                .change_property8(
+                    PropMode::REPLACE,
+                    window,
+                    AtomEnum::WM_CLIENT_MACHINE,
+                    AtomEnum::STRING,
+                    hostname.as_bytes(),
+                )
+                .unwrap();
+        }
+
+        client.connection.map_window(window).unwrap();
+        client.sync();
+        client
+    }
+
+    fn set_property32(&self, name: &str, type_: AtomEnum, values: &[u32]) {
+        let atom = self
+            .connection
+            .intern_atom(false, name.as_bytes())
+            .unwrap()
+            .reply()
+            .unwrap()
+            .atom;
+
+        self.connection
+            .change_property32(PropMode::REPLACE, self.window, atom, type_, values)
+            .unwrap();
+        self.sync();
+    }
+
+    /// Waits until the X server processes the requests.
+    fn sync(&self) {
+        self.connection.get_input_focus().unwrap().reply().unwrap();
+    }
+
+    fn x11_window(&self) -> X11Window {
+        X11Window::new(X11Connection::connect().unwrap(), self.window)
+    }
+}
+
+fn hostname() -> String {
+    std::fs::read_to_string("/proc/sys/kernel/hostname")
+        .unwrap()
+        .trim_end()
+        .to_owned()
+}
+
+#[test]
+#[ignore = "requires an X server"]
+fn lists_the_mapped_windows() {
+    let client = Client::spawn(true);
+
+    let window = x11::get_windows()
+        .unwrap()
+        .into_iter()
+        .find(|window| window.id().as_u32() == client.window)
+        .expect("the window of the client is not listed");
+
+    assert_eq!(window.title().unwrap().as_deref(), Some("window-getter"));
+    assert_eq!(window.bounds().unwrap(), Bounds::new(10., 20., 300., 200.));
+    assert_eq!(window.owner_pid().unwrap(), std::process::id() as i32);
+    assert!(window.owner_name().unwrap().is_some());
+
+    let snapshot = window.snapshot();
+    assert!(snapshot.errors.is_empty(), "{:?}", snapshot.errors);
+    assert_eq!(snapshot.owner_name, window.owner_name().unwrap());
+}
+
+#[test]
+#[ignore = "requires an X server"]
+fn windows_are_in_front_to_back_order() {
+    let back = Client::spawn(true);
+    let front = Client::spawn(true);
+
+    let ids = x11::get_windows()
+        .unwrap()
+        .into_iter()
+        .map(|window| window.id().as_u32())
+        .filter(|&id| id == back.window || id == front.window)
+        .collect::<Vec<_>>();
+
+    assert_eq!(ids, [front.window, back.window]);
+}
+
+#[test]
+#[ignore = "requires an X server"]
+fn points_out_of_the_x11_coordinates_have_no_window() {
+    let _client = Client::spawn(true);
+    let query = WindowQuery::new();
+
+    assert!(x11::find_window_at(&query, 15.5, 25.5).unwrap().is_some());
+    // The point would wrap around to `(15, 25)` if it was cast to `i16`.
+    assert!(
+        x11::find_window_at(&query, 15.5 + 65536.0, 25.5)
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        x11::find_window_at(&query, f64::NAN, 25.5)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+#[ignore = "requires an X server"]
+fn bounds_include_the_frame_extents() {
+    let client = Client::spawn(true);
+    client.set_property32("_NET_FRAME_EXTENTS", AtomEnum::CARDINAL, &[1, 2, 3, 4]);
+
+    let bounds = x11::get_window(client.window)
+        .unwrap()
+        .unwrap()
+        .bounds()
+        .unwrap();
+
+    assert_eq!(bounds, Bounds::new(9., 17., 303., 207.));
+}
+
+#[test]
+#[ignore = "requires an X server"]
+fn owner_pid_is_read_from_net_wm_pid() {
+    let client = Client::spawn(true);
+    let window = client.x11_window();
+
+    assert_eq!(
+        window.owner_pid_with_source().unwrap(),
+        (std::process::id(), X11PidSource::NetWmPid)
+    );
+    assert_eq!(window.local_owner_pid().unwrap(), Some(std::process::id()));
+}
+
+#[test]
+#[ignore = "requires an X server"]
+fn owner_pid_falls_back_to_xres() {
+    let client = Client::spawn(false);
+    let window = client.x11_window();
+
+    assert_eq!(
+        window.owner_pid_with_source().unwrap(),
+        (std::process::id(), X11PidSource::XRes)
+    );
+    assert_eq!(window.local_owner_pid().unwrap(), Some(std::process::id()));
+    assert!(window.owner_name().unwrap().is_some());
+
+    let connection = X11Connection::connect().unwrap();
+    assert_eq!(
+        connection.owner_pids(&[client.window]).unwrap(),
+        [Some(std::process::id())]
+    );
+}